@@ -0,0 +1,1015 @@
+#!/usr/bin/env python3
+"""Prepare and run the ECDSA benchmark suites.
+
+Subcommands:
+    setup      Download and cache the Powers of Tau, run the circom circuits' Groth16
+               setup, and record checksums of everything under the artifacts directory
+    witnesses  Pre-generate and cache the circom stacks' witnesses for the current
+               test cases, timing witness generation on its own
+    run        Run the benchmark matrix declared in benchmarks.toml, tagging every
+               result row with its stack, curve, batch size, thread count, and test cases;
+               --resume continues an interrupted matrix from its state.json
+    device     Cross-compile the native provers for ARM, run them on a phone (adb) or
+               board (ssh), and pull the results back in the usual layout
+"""
+import argparse
+import csv
+import datetime
+import hashlib
+import itertools
+import json
+import math
+import os
+import shlex
+import shutil
+import string
+import struct
+import subprocess
+import sys
+import tempfile
+import time
+import tomllib
+import urllib.request
+from pathlib import Path
+
+REPO_ROOT = Path(__file__).resolve().parent
+DEFAULT_ARTIFACTS_DIR = REPO_ROOT / 'artifacts'
+DEFAULT_CONFIG = REPO_ROOT / 'benchmarks.toml'
+
+sys.path.insert(0, str(REPO_ROOT / 'ec2-benchmarks'))
+from bench_results import flatten_metrics, load_results  # noqa: E402
+
+# Stacks proving with a circuit-specific Groth16 setup over a Powers of Tau
+GROTH16_STACKS = ['snarkjs', 'rapidsnark']
+
+# Hermez ceremony files, one per power (2^power constraints)
+PTAU_URL = 'https://storage.googleapis.com/zkevm/ptau/powersOfTau28_hez_final_{power:02d}.ptau'
+PTAU_MIN_POWER = 8
+PTAU_MAX_POWER = 28
+# blake2b-512 of each ceremony file by power, as published in the snarkjs README.
+# Every file is checked against it before its first use; a power missing here
+# needs its published hash passed with --ptau-blake2b N=HASH.
+PTAU_BLAKE2B = {}
+
+SETUP_FILE = 'setup.json'
+CHECKSUMS_FILE = 'checksums.sha256'
+
+STACKS = ['snarkjs', 'rapidsnark', 'rapidsnark-gpu', 'noir', 'gnark']
+
+# Opt-in GPU stacks, built from their CPU stack's directory on a CUDA base image
+GPU_STACKS = {'rapidsnark-gpu': 'rapidsnark'}
+GPU_BASE_IMAGE = 'nvidia/cuda:12.2.2-devel-ubuntu22.04'
+
+# The generator and every circuit verify P-256 signatures only
+SUPPORTED_CURVES = ['p256']
+
+# benchmarks.toml: every combination of the [matrix] lists is one run
+MATRIX_DEFAULTS = {
+    'stacks': [stack for stack in STACKS if stack not in GPU_STACKS],
+    'curves': ['p256'],
+    'batch_sizes': [1],
+    'threads': [0],
+    'test_cases': [10],
+}
+OPTION_DEFAULTS = {
+    'runs': 1,
+    'warmup': None,
+    'verify_runs': 10,
+    'include_invalid': False,
+    'cache_witnesses': False,
+    'output_dir': 'results',
+}
+
+# Stacks with a native prover that device/Dockerfile cross-compiles for ARM
+DEVICE_STACKS = ['rapidsnark', 'gnark']
+# Where `device` pushes the binaries and fixtures, per transport (ssh paths are
+# relative to the login directory)
+DEVICE_REMOTE_DIRS = {'adb': '/data/local/tmp/zk-ecdsa-benchmarks', 'ssh': 'zk-ecdsa-benchmarks'}
+
+# Columns of results.csv, one row per (run, phase, metric)
+RESULT_COLUMNS = ['run', 'stack', 'curve', 'batch_size', 'threads', 'test_cases', 'phase', 'metric', 'value']
+
+# Per-run status of a matrix and where its test cases are snapshotted, rewritten
+# after every run so `run --resume` can pick up where an interrupted session stopped
+STATE_FILE = 'state.json'
+
+
+def sha256_file(path):
+    """SHA-256 of a file, read in chunks so multi-gigabyte ptau files fit in memory."""
+    digest = hashlib.sha256()
+    with open(path, 'rb') as f:
+        for chunk in iter(lambda: f.read(1 << 20), b''):
+            digest.update(chunk)
+    return digest.hexdigest()
+
+
+def read_checksums(artifacts_dir):
+    """Recorded checksums as {relative path: sha256}, in `sha256sum` format."""
+    path = artifacts_dir / CHECKSUMS_FILE
+    if not path.is_file():
+        return {}
+    checksums = {}
+    for line in path.read_text().splitlines():
+        if line.strip():
+            digest, name = line.split(maxsplit=1)
+            checksums[name.lstrip('*')] = digest
+    return checksums
+
+
+def write_checksums(artifacts_dir, checksums):
+    lines = [f"{digest}  {name}\n" for name, digest in sorted(checksums.items())]
+    (artifacts_dir / CHECKSUMS_FILE).write_text(''.join(lines))
+
+
+def verify_checksums(artifacts_dir, checksums, names=None):
+    """Check recorded files against their checksums and return the names that don't match."""
+    mismatched = []
+    for name in sorted(names if names is not None else checksums):
+        path = artifacts_dir / name
+        if not path.is_file() or sha256_file(path) != checksums.get(name):
+            mismatched.append(name)
+    return mismatched
+
+
+def r1cs_info(path):
+    """Constraint and public signal counts from the header section of a circom .r1cs file."""
+    with open(path, 'rb') as f:
+        magic, _version, num_sections = struct.unpack('<4sII', f.read(12))
+        if magic != b'r1cs':
+            sys.exit(f"Error: {path} is not an r1cs file")
+        for _ in range(num_sections):
+            section_type, section_size = struct.unpack('<IQ', f.read(12))
+            if section_type != 1:
+                f.seek(section_size, 1)
+                continue
+            (field_size,) = struct.unpack('<I', f.read(4))
+            f.seek(field_size, 1)
+            _wires, public_outputs, public_inputs, _private_inputs, _labels, constraints = \
+                struct.unpack('<IIIIQI', f.read(28))
+            return {'constraints': constraints, 'public_signals': public_outputs + public_inputs}
+    sys.exit(f"Error: {path} has no header section")
+
+
+def required_power(info):
+    """Smallest Powers of Tau that fits the circuit, as snarkjs sizes the Groth16 domain."""
+    power = max(PTAU_MIN_POWER, math.ceil(math.log2(info['constraints'] + info['public_signals'] + 1)))
+    if power > PTAU_MAX_POWER:
+        sys.exit(f"Error: {info['constraints']:,} constraints need 2^{power}, beyond the largest "
+                 f"Powers of Tau (2^{PTAU_MAX_POWER})")
+    return power
+
+
+def download(url, path):
+    """Download `url` to `path` via a .part file, so an interrupted download is never mistaken for a complete one."""
+    partial = path.with_name(path.name + '.part')
+    print(f"⬇️  Downloading {url}")
+    with urllib.request.urlopen(url) as response, open(partial, 'wb') as f:
+        total = int(response.headers.get('Content-Length') or 0)
+        done = 0
+        for chunk in iter(lambda: response.read(1 << 20), b''):
+            f.write(chunk)
+            done += len(chunk)
+            if total:
+                print(f"\r   {done / total:6.1%} of {total / (1 << 30):.2f} GB", end='', flush=True)
+        print()
+    if total and partial.stat().st_size != total:
+        partial.unlink()
+        sys.exit(f"Error: download of {url} was truncated")
+    partial.rename(path)
+
+
+def ptau_blake2b_arg(value):
+    """Parse a --ptau-blake2b N=HASH argument into (power, hash)."""
+    power, _, digest = value.partition('=')
+    if (not power.isdigit() or not PTAU_MIN_POWER <= int(power) <= PTAU_MAX_POWER
+            or len(digest) != 128 or any(c not in string.hexdigits for c in digest)):
+        raise argparse.ArgumentTypeError(f"expected N=HASH with N in {PTAU_MIN_POWER}..{PTAU_MAX_POWER} "
+                                         f"and a 128-digit hex blake2b-512, got '{value}'")
+    return int(power), digest.lower()
+
+
+def ptau_hashes(args):
+    """Published Powers of Tau hashes by power, with those passed on the command line."""
+    return {**PTAU_BLAKE2B, **dict(args.ptau_blake2b)}
+
+
+def require_ptau_hash(power, hashes):
+    if power not in hashes:
+        sys.exit(f"Error: no published blake2b hash for powersOfTau28_hez_final_{power:02d}.ptau; pass "
+                 f"the one from the snarkjs README with --ptau-blake2b {power}=HASH")
+
+
+def fetch_ptau(artifacts_dir, power, checksums, hashes):
+    """Return the cached Powers of Tau for `power`, downloading it if needed. A new file must
+    match its published blake2b-512 hash in `hashes`, and a cached one the checksum recorded after that."""
+    name = f"ptau/{Path(PTAU_URL.format(power=power)).name}"
+    path = artifacts_dir / name
+    if path.is_file() and name in checksums:
+        print(f"🔍 Verifying cached {name}...")
+        if verify_checksums(artifacts_dir, checksums, [name]):
+            sys.exit(f"Error: {path} does not match its recorded checksum; delete it to download it again")
+    else:
+        require_ptau_hash(power, hashes)
+        expected = hashes[power]
+        path.parent.mkdir(parents=True, exist_ok=True)
+        downloaded = not path.is_file()
+        if downloaded:
+            download(PTAU_URL.format(power=power), path)
+        print(f"🔍 Verifying {name} against its published hash...")
+        with open(path, 'rb') as f:
+            digest = hashlib.file_digest(f, 'blake2b').hexdigest()
+        if digest != expected:
+            if downloaded:
+                path.unlink()
+            sys.exit(f"Error: {path} has blake2b {digest}, expected {expected}")
+        checksums[name] = sha256_file(path)
+    print(f"✅ {name} (sha256 {checksums[name][:16]}...)")
+    return name
+
+
+def circuit_fingerprint(stack):
+    """Hash of everything the compiled circuit depends on: the circom sources and the
+    batch size and chunk layout recorded in the stack's test manifest. The suites'
+    run.sh and run-all-benchmarks.sh compute the same hash with jq to check a cached setup."""
+    digest = hashlib.sha256()
+    stack_dir = REPO_ROOT / stack
+    for source in ['circuit.circom', 'batch.circom']:
+        if (stack_dir / source).is_file():
+            digest.update((stack_dir / source).read_bytes())
+    manifest_path = stack_dir / 'tests' / 'manifest.json'
+    manifest = json.loads(manifest_path.read_text()) if manifest_path.is_file() else {}
+    digest.update(json.dumps({
+        'batch_size': manifest.get('batch_size', 1),
+        'chunk_layout': manifest.get('chunk_layout'),
+    }, sort_keys=True, separators=(',', ':')).encode())
+    return digest.hexdigest()
+
+
+def stack_dir(stack):
+    """The directory a stack is built from (GPU stacks share their CPU stack's)."""
+    return GPU_STACKS.get(stack, stack)
+
+
+def build_image(stack):
+    build_args = ['--build-arg', f"BASE_IMAGE={GPU_BASE_IMAGE}", '--build-arg', 'GPU=1'] if stack in GPU_STACKS else []
+    command = ['docker', 'build', *build_args, '-t', f"zk-ecdsa-{stack}", str(REPO_ROOT / stack_dir(stack))]
+    if subprocess.run(command).returncode != 0:
+        sys.exit(f"Error: could not build the {stack} image")
+
+
+def cuda_available():
+    """Whether this host has an NVIDIA GPU that Docker can pass to containers."""
+    try:
+        gpus = subprocess.run(['nvidia-smi', '-L'], capture_output=True, text=True)
+        runtimes = subprocess.run(['docker', 'info', '--format', '{{json .Runtimes}}'], capture_output=True, text=True)
+    except FileNotFoundError:
+        return False
+    return gpus.returncode == 0 and bool(gpus.stdout.strip()) and 'nvidia' in runtimes.stdout
+
+
+def ptau_mount(ptau_path):
+    """Docker arguments mounting a Powers of Tau where the suite scripts look for it."""
+    return ['-v', f"{ptau_path}:/app/{ptau_path.name}:ro", '-e', f"PTAU={ptau_path.name}"]
+
+
+def docker_run(stack, script, out_dir, extra_args=()):
+    """Run one of the stack's scripts in its benchmark image with the artifacts as /out."""
+    command = ['docker', 'run', '--rm', '--entrypoint', f"/app/scripts/{script}",
+               '-v', f"{REPO_ROOT / stack / 'tests'}:/app/tests:ro",
+               '-v', f"{out_dir}:/out", *extra_args, f"zk-ecdsa-{stack}"]
+    if subprocess.run(command).returncode != 0:
+        sys.exit(f"Error: {script} failed for {stack}")
+
+
+def setup_stack(artifacts_dir, stack, setup, checksums, ptau_hashes, power=None, force=False):
+    """Compile `stack`'s circuit and run its Groth16 setup into the artifacts directory,
+    unless the cached setup still matches the circuit and its checksums."""
+    out_dir = artifacts_dir / stack
+    setup_files = [f"{stack}/setup/circuit.zkey", f"{stack}/setup/verification_key.json"]
+    fingerprint = circuit_fingerprint(stack)
+    cached = setup['stacks'].get(stack, {})
+    if (not force and cached.get('circuit_fingerprint') == fingerprint
+            and all(name in checksums for name in setup_files)
+            and not verify_checksums(artifacts_dir, checksums, setup_files + [cached['ptau']])):
+        print(f"✅ {stack}: cached setup matches the circuit and its checksums, skipping")
+        return
+
+    print(f"🔨 {stack}: building the image and compiling the circuit...")
+    build_image(stack)
+    docker_run(stack, 'compile-circuit.sh', out_dir)
+
+    info = r1cs_info(out_dir / 'setup' / 'circuit.r1cs')
+    power = power or required_power(info)
+    print(f"📐 {stack}: {info['constraints']:,} constraints, using Powers of Tau 2^{power}")
+    ptau = fetch_ptau(artifacts_dir, power, checksums, ptau_hashes)
+    write_checksums(artifacts_dir, checksums)
+
+    print(f"🔑 {stack}: running the Groth16 setup...")
+    docker_run(stack, 'trusted-setup.sh', out_dir, ptau_mount(artifacts_dir / ptau))
+
+    for name in setup_files + [f"{stack}/setup/circuit.r1cs"]:
+        checksums[name] = sha256_file(artifacts_dir / name)
+    write_checksums(artifacts_dir, checksums)
+    setup['stacks'][stack] = {
+        'circuit_fingerprint': fingerprint,
+        'constraints': info['constraints'],
+        'ptau': ptau,
+        'ptau_power': power,
+        'created_at': datetime.datetime.now(datetime.timezone.utc).strftime('%Y-%m-%dT%H:%M:%SZ'),
+    }
+    save_setup(artifacts_dir, setup)
+    print(f"✅ {stack}: setup saved to {out_dir / 'setup'}")
+
+
+def fixtures_fingerprint(stack):
+    """Hash of the stack's generated test case files."""
+    digest = hashlib.sha256()
+    for path in sorted((REPO_ROOT / stack / 'tests').glob('*')):
+        digest.update(path.name.encode())
+        digest.update(path.read_bytes())
+    return digest.hexdigest()
+
+
+def witness_cache(stack, threads=0):
+    """Name of the witness cache for `stack` timed on `threads` cores (0 = all of them)."""
+    return f"{stack}_t{threads}" if threads else stack
+
+
+def cpuset_args(threads):
+    """Docker arguments pinning a container to the first `threads` cores (0 = no pin).
+    The suites size their worker pools from the visible cores, so this caps the threads they use."""
+    return [f"--cpuset-cpus=0-{threads - 1}"] if threads else []
+
+
+def cache_witnesses(artifacts_dir, stack, setup, env=(), force=False, threads=0):
+    """Generate `stack`'s witnesses for the current test cases into artifacts/witnesses/<stack>
+    (<stack>_t<threads> when pinned to `threads` cores), timed as in a full run on those cores,
+    unless they are already cached for these test cases and circuit. Needs the stack's setup
+    from setup_stack."""
+    name = witness_cache(stack, threads)
+    cache_dir = artifacts_dir / 'witnesses' / name
+    fingerprint = hashlib.sha256(
+        (fixtures_fingerprint(stack) + setup['stacks'][stack]['circuit_fingerprint']).encode()).hexdigest()
+    cached = setup.get('witnesses', {}).get(name, {})
+    if (not force and cached.get('fingerprint') == fingerprint
+            and (cache_dir / 'benchmarks' / 'all_witnesses_benchmark.json').is_file()):
+        print(f"✅ {name}: cached witnesses match the test cases, skipping")
+        return
+
+    print(f"🧮 {name}: generating witnesses...")
+    shutil.rmtree(cache_dir, ignore_errors=True)
+    cache_dir.mkdir(parents=True)
+    docker_run(stack, 'compute-witnesses.sh', cache_dir,
+               ['-v', f"{artifacts_dir / stack / 'setup'}:/out/setup:ro", *cpuset_args(threads), *env])
+    setup.setdefault('witnesses', {})[name] = {
+        'fingerprint': fingerprint,
+        'threads': threads,
+        'witnesses': len(list((cache_dir / 'witnesses').glob('*.wtns'))),
+        'created_at': datetime.datetime.now(datetime.timezone.utc).strftime('%Y-%m-%dT%H:%M:%SZ'),
+    }
+    save_setup(artifacts_dir, setup)
+    print(f"✅ {name}: witnesses saved to {cache_dir}")
+
+
+def timing_env(runs, warmup):
+    """Docker arguments setting the suites' timed and warmup runs."""
+    env = ['-e', f"BENCH_RUNS={runs}"]
+    if warmup is not None:
+        env += ['-e', f"BENCH_WARMUP={warmup}"]
+    return env
+
+
+def load_setup(artifacts_dir):
+    """The artifacts directory's setup.json and checksums, creating the directory if needed."""
+    artifacts_dir.mkdir(parents=True, exist_ok=True)
+    setup_path = artifacts_dir / SETUP_FILE
+    setup = json.loads(setup_path.read_text()) if setup_path.is_file() else {'stacks': {}}
+    return setup, read_checksums(artifacts_dir)
+
+
+def save_setup(artifacts_dir, setup):
+    (artifacts_dir / SETUP_FILE).write_text(json.dumps(setup, indent=2) + '\n')
+
+
+def cmd_setup(args):
+    artifacts_dir = Path(args.artifacts_dir).resolve()
+    setup, checksums = load_setup(artifacts_dir)
+
+    if args.verify:
+        mismatched = verify_checksums(artifacts_dir, checksums)
+        for name in mismatched:
+            print(f"❌ {name}")
+        print(f"{len(checksums) - len(mismatched)}/{len(checksums)} artifact(s) match {CHECKSUMS_FILE}")
+        return 1 if mismatched or not checksums else 0
+
+    hashes = ptau_hashes(args)
+    if args.power and f"ptau/{Path(PTAU_URL.format(power=args.power)).name}" not in checksums:
+        require_ptau_hash(args.power, hashes)
+    for stack in args.stacks:
+        setup_stack(artifacts_dir, stack, setup, checksums, hashes, args.power, args.force)
+
+    print(f"✅ Artifacts are in {artifacts_dir} (checksums in {CHECKSUMS_FILE})")
+    return 0
+
+
+def cmd_witnesses(args):
+    artifacts_dir = Path(args.artifacts_dir).resolve()
+    setup, checksums = load_setup(artifacts_dir)
+    for stack in args.stacks:
+        if not any((REPO_ROOT / stack / 'tests').glob('test_case_*.json')):
+            sys.exit(f"Error: no test cases in {stack}/tests; run generate_test_cases first")
+        setup_stack(artifacts_dir, stack, setup, checksums, ptau_hashes(args))
+        cache_witnesses(artifacts_dir, stack, setup, timing_env(args.runs, args.warmup), args.force)
+    return 0
+
+
+def load_config(path):
+    """Read a benchmarks.toml, filling in defaults and exiting on unknown keys or invalid values."""
+    try:
+        with open(path, 'rb') as f:
+            config = tomllib.load(f)
+    except FileNotFoundError:
+        sys.exit(f"Error: {path} not found")
+    except tomllib.TOMLDecodeError as e:
+        sys.exit(f"Error: Invalid TOML in {path}: {e}")
+
+    unknown = sorted(set(config) - {'matrix', 'options'})
+    if unknown:
+        sys.exit(f"Error: unknown section [{unknown[0]}] in {path}")
+    matrix = {**MATRIX_DEFAULTS, **config.get('matrix', {})}
+    options = {**OPTION_DEFAULTS, **config.get('options', {})}
+    for section, values, defaults in [('matrix', matrix, MATRIX_DEFAULTS), ('options', options, OPTION_DEFAULTS)]:
+        unknown = sorted(set(values) - set(defaults))
+        if unknown:
+            sys.exit(f"Error: unknown key {unknown[0]} in [{section}] of {path}")
+
+    is_int = lambda value, minimum: isinstance(value, int) and not isinstance(value, bool) and value >= minimum
+    for key, valid, expected in [
+        ('stacks', lambda v: v in STACKS, f"one of {', '.join(STACKS)}"),
+        ('curves', lambda v: v in SUPPORTED_CURVES, f"one of {', '.join(SUPPORTED_CURVES)}"),
+        ('batch_sizes', lambda v: is_int(v, 1), 'a positive integer'),
+        ('threads', lambda v: is_int(v, 0), 'a non-negative integer (0 for all cores)'),
+        ('test_cases', lambda v: is_int(v, 1), 'a positive integer'),
+    ]:
+        values = matrix[key]
+        if not isinstance(values, list) or not values:
+            sys.exit(f"Error: matrix.{key} must be a non-empty list")
+        for value in values:
+            if not valid(value):
+                sys.exit(f"Error: matrix.{key} entry {value!r} must be {expected}")
+        if len(set(values)) != len(values):
+            sys.exit(f"Error: matrix.{key} has duplicate entries")
+
+    for key, valid, expected in [
+        ('runs', lambda v: is_int(v, 1), 'a positive integer'),
+        ('warmup', lambda v: v is None or is_int(v, 0), 'a non-negative integer'),
+        ('verify_runs', lambda v: is_int(v, 1), 'a positive integer'),
+        ('include_invalid', lambda v: isinstance(v, bool), 'true or false'),
+        ('cache_witnesses', lambda v: isinstance(v, bool), 'true or false'),
+        ('output_dir', lambda v: isinstance(v, str) and v, 'a directory path'),
+    ]:
+        if not valid(options[key]):
+            sys.exit(f"Error: options.{key} must be {expected}")
+    return {'matrix': matrix, 'options': options}
+
+
+def expand_matrix(matrix):
+    """Expand the matrix into runs, ordered so runs sharing test cases are adjacent."""
+    runs = []
+    for curve, batch_size, test_cases in itertools.product(
+            matrix['curves'], matrix['batch_sizes'], matrix['test_cases']):
+        for stack, threads in itertools.product(matrix['stacks'], matrix['threads']):
+            runs.append({
+                'run': f"{stack}_{curve}_b{batch_size}_t{threads or 'all'}_n{test_cases}",
+                'stack': stack,
+                'curve': curve,
+                'batch_size': batch_size,
+                'threads': threads,
+                'test_cases': test_cases,
+            })
+    return runs
+
+
+def fixture_key(run):
+    return (run['curve'], run['batch_size'], run['test_cases'])
+
+
+def generate_fixtures(run, include_invalid):
+    """Regenerate every stack's test cases for a run's curve, batch size, and test case count."""
+    command = ['cargo', 'run', '--release', '--quiet', '--bin', 'generate_test_cases', '--',
+               '--num-test-cases', str(run['test_cases']), '--batch-size', str(run['batch_size'])]
+    if include_invalid:
+        command.append('--include-invalid')
+    print(f"🧪 Generating {run['test_cases']} {run['curve']} test case(s) of batch size {run['batch_size']}...")
+    if subprocess.run(command, cwd=REPO_ROOT).returncode != 0:
+        sys.exit("Error: test case generation failed")
+
+
+def fixtures_name(run):
+    return f"{run['curve']}_b{run['batch_size']}_n{run['test_cases']}"
+
+
+def snapshot_fixtures(matrix_dir, run, state):
+    """Copy the test cases just generated for a run's fixture group into the matrix directory,
+    so a resumed matrix benchmarks its remaining runs on the same test cases."""
+    name = fixtures_name(run)
+    for stack in [stack for stack in STACKS if stack not in GPU_STACKS]:
+        shutil.copytree(REPO_ROOT / stack / 'tests', matrix_dir / 'fixtures' / name / stack, dirs_exist_ok=True)
+    state['fixtures'][name] = f"fixtures/{name}"
+    save_state(matrix_dir, state)
+
+
+def restore_fixtures(matrix_dir, path):
+    """Put a fixture group's snapshotted test cases back in every stack's tests directory."""
+    print(f"♻️  Restoring the test cases in {path}...")
+    for snapshot in sorted((matrix_dir / path).iterdir()):
+        tests_dir = REPO_ROOT / snapshot.name / 'tests'
+        shutil.rmtree(tests_dir, ignore_errors=True)
+        shutil.copytree(snapshot, tests_dir)
+
+
+def benchmark_run(run, out_dir, options, artifacts_dir, setup, resume=False):
+    """Run one stack's full benchmark in its image, limited to the run's thread count. With `resume`,
+    test cases already timed in out_dir by an earlier attempt are not timed again. Returns success."""
+    command = ['docker', 'run', '--rm',
+               '-v', f"{REPO_ROOT / stack_dir(run['stack']) / 'tests'}:/app/tests:ro",
+               '-v', f"{out_dir}:/out",
+               *timing_env(options['runs'], options['warmup']), '-e', f"VERIFY_RUNS={options['verify_runs']}"]
+    command += cpuset_args(run['threads'])
+    if resume:
+        command += ['-e', 'RESUME=1']
+    git_commit = subprocess.run(['git', 'rev-parse', 'HEAD'], cwd=REPO_ROOT,
+                                capture_output=True, text=True).stdout.strip()
+    if git_commit:
+        command += ['-e', f"GIT_COMMIT={git_commit}"]
+    groth16_stack = stack_dir(run['stack'])
+    if groth16_stack in GROTH16_STACKS:
+        command += ['-v', f"{artifacts_dir / groth16_stack}:/artifacts:ro",
+                    '-e', f"CIRCUIT_FINGERPRINT={setup['stacks'][groth16_stack]['circuit_fingerprint']}",
+                    *ptau_mount(artifacts_dir / setup['stacks'][groth16_stack]['ptau'])]
+        if options['cache_witnesses']:
+            # Witnesses cached on the same cores, so their timing is this run's own
+            command += ['-v', f"{artifacts_dir / 'witnesses' / witness_cache(groth16_stack, run['threads'])}:/witnesses:ro"]
+    if run['stack'] in GPU_STACKS:
+        command += ['--gpus', 'all']
+    command.append(f"zk-ecdsa-{run['stack']}")
+    return subprocess.run(command).returncode == 0
+
+
+def timed_test_cases(out_dir):
+    """How many per-test-case results (witnesses, proofs, or verifications) a suite has kept in out_dir."""
+    return sum(1 for _ in (out_dir / 'benchmarks').glob('*/test_case_*.json'))
+
+
+def summarize_run(run_dir):
+    """Build the run's performance_data.json and return its metrics as result rows."""
+    subprocess.run([str(REPO_ROOT / 'ec2-benchmarks' / 'scripts' / 'summarize-results.sh'),
+                    str(run_dir), str(run_dir / 'summary')], check=True, stdout=subprocess.DEVNULL)
+    return run_rows(run_dir)
+
+
+def run_rows(run_dir):
+    """The metrics of a summarized run as result rows."""
+    metrics = flatten_metrics(load_results(run_dir / 'summary'))
+    return [{'phase': phase, 'metric': metric, 'value': value}
+            for (_, phase, metric), value in sorted(metrics.items())]
+
+
+def format_duration(seconds):
+    return str(datetime.timedelta(seconds=round(seconds)))
+
+
+def progress_bar(done, total, durations, width=24):
+    """A progress bar for `done` of `total` runs, with an ETA from the durations of the runs timed so far."""
+    filled = round(width * done / total)
+    bar = f"{'█' * filled}{'░' * (width - filled)} {done}/{total}"
+    if durations and done < total:
+        bar += f" · ETA {format_duration(sum(durations) / len(durations) * (total - done))}"
+    return bar
+
+
+def save_state(matrix_dir, state):
+    """Write the matrix's state.json, replacing it atomically so a crash never leaves it half-written."""
+    path = matrix_dir / STATE_FILE
+    path.with_suffix('.tmp').write_text(json.dumps(state, indent=2) + '\n')
+    path.with_suffix('.tmp').replace(path)
+
+
+def resume_dir(output_dir, resume):
+    """The matrix directory to resume: `resume` itself, or the latest one with a state file for 'latest'."""
+    if resume != 'latest':
+        matrix_dir = Path(resume).resolve()
+    else:
+        candidates = sorted(output_dir.glob(f"matrix_*/{STATE_FILE}"))
+        if not candidates:
+            sys.exit(f"Error: no matrix run to resume in {output_dir}")
+        matrix_dir = candidates[-1].parent
+    if not (matrix_dir / STATE_FILE).is_file():
+        sys.exit(f"Error: {matrix_dir} has no {STATE_FILE} to resume from")
+    return matrix_dir
+
+
+def cmd_run(args):
+    config = load_config(args.config)
+    options = config['options']
+    runs = expand_matrix(config['matrix'])
+
+    cores = os.cpu_count()
+    for run in [run for run in runs if run['threads'] > cores]:
+        print(f"⚠️  Skipping {run['run']}: {run['threads']} threads but only {cores} cores")
+        runs.remove(run)
+    if any(run['stack'] in GPU_STACKS for run in runs) and not cuda_available():
+        for run in [run for run in runs if run['stack'] in GPU_STACKS]:
+            print(f"⚠️  Skipping {run['run']}: no NVIDIA GPU with the NVIDIA container runtime")
+            runs.remove(run)
+
+    print(f"📋 {len(runs)} run(s) from {args.config}:")
+    for run in runs:
+        print(f"   {run['run']}")
+    if args.dry_run or not runs:
+        return 0
+
+    artifacts_dir = Path(args.artifacts_dir).resolve()
+    output_dir = Path(args.output_dir or options['output_dir']).resolve()
+    if args.resume:
+        matrix_dir = resume_dir(output_dir, args.resume)
+        state = json.loads((matrix_dir / STATE_FILE).read_text())
+        if state['config'] != config:
+            sys.exit(f"Error: the matrix in {args.config} differs from the one {matrix_dir.name} was started with; "
+                     f"run without --resume to start a new matrix")
+        done = sum(state['runs'].get(run['run'], {}).get('status') == 'completed' for run in runs)
+        print(f"⏯️  Resuming {matrix_dir.name}: {done}/{len(runs)} run(s) already completed")
+    else:
+        matrix_dir = output_dir / f"matrix_{datetime.datetime.now().strftime('%Y%m%d_%H%M%S')}"
+        matrix_dir.mkdir(parents=True)
+        state = {'config': config, 'fixtures': {}, 'runs': {}}
+    for run in runs:
+        state['runs'].setdefault(run['run'], {'status': 'pending'})
+    save_state(matrix_dir, state)
+
+    def completed(run):
+        return state['runs'][run['run']]['status'] == 'completed'
+
+    stacks = sorted({run['stack'] for run in runs if not completed(run)}, key=STACKS.index)
+    for stack in stacks:
+        print(f"🔨 Building the {stack} image...")
+        build_image(stack)
+
+    setup, checksums = load_setup(artifacts_dir)
+    # Durations of the runs timed in this session, for the ETA
+    durations = []
+    current = None
+    # Runs that failed in this session: done for the progress, unlike ones that
+    # failed in an earlier session and are being run again
+    failed_now = []
+    try:
+        for _, group in itertools.groupby(runs, key=fixture_key):
+            group = [run for run in group if not completed(run)]
+            if not group:
+                continue
+            # Runs of a group resumed after some of them completed must see the same test cases
+            if fixtures_name(group[0]) in state['fixtures']:
+                restore_fixtures(matrix_dir, state['fixtures'][fixtures_name(group[0])])
+            else:
+                generate_fixtures(group[0], options['include_invalid'])
+                snapshot_fixtures(matrix_dir, group[0], state)
+            for stack in [stack for stack in GROTH16_STACKS if any(stack_dir(run['stack']) == stack for run in group)]:
+                setup_stack(artifacts_dir, stack, setup, checksums, ptau_hashes(args))
+                if options['cache_witnesses']:
+                    for threads in sorted({run['threads'] for run in group if stack_dir(run['stack']) == stack}):
+                        cache_witnesses(artifacts_dir, stack, setup, timing_env(options['runs'], options['warmup']),
+                                        threads=threads)
+
+            for run in group:
+                done = sum(completed(other) for other in runs) + len(failed_now)
+                print(f"🚀 [{done + 1}/{len(runs)}] {run['run']}  {progress_bar(done, len(runs), durations)}")
+                run_dir = matrix_dir / run['run']
+                # A run interrupted or failed in an earlier session keeps the test cases it timed
+                resume = state['runs'][run['run']]['status'] != 'pending' and run_dir.is_dir()
+                if resume:
+                    timed = timed_test_cases(run_dir / run['stack'])
+                    print(f"⏯️  Continuing {run['run']}, keeping {timed} test case result(s) timed earlier")
+                (run_dir / run['stack']).mkdir(parents=True, exist_ok=True)
+                (run_dir / 'config.json').write_text(json.dumps(run, indent=2) + '\n')
+
+                current = run
+                state['runs'][run['run']] = {'status': 'running'}
+                save_state(matrix_dir, state)
+                start = time.monotonic()
+                succeeded = benchmark_run(run, run_dir / run['stack'], options, artifacts_dir, setup, resume)
+                if succeeded:
+                    summarize_run(run_dir)
+                else:
+                    print(f"❌ {run['run']} failed")
+                    failed_now.append(run['run'])
+                durations.append(time.monotonic() - start)
+                state['runs'][run['run']] = {'status': 'completed' if succeeded else 'failed',
+                                             'duration_seconds': round(durations[-1], 1)}
+                save_state(matrix_dir, state)
+                current = None
+    except KeyboardInterrupt:
+        if current:
+            state['runs'][current['run']] = {'status': 'interrupted'}
+            save_state(matrix_dir, state)
+        print(f"\n⏸️  Interrupted, resume with: python3 bench.py run --config {args.config} --resume {matrix_dir}")
+        return 130
+    print(f"🏁 {progress_bar(len(runs), len(runs), durations)}")
+
+    statuses = [{**run, **state['runs'][run['run']]} for run in runs]
+    rows = [{**run, **row} for run in runs if completed(run) for row in run_rows(matrix_dir / run['run'])]
+    (matrix_dir / 'results.json').write_text(json.dumps({'config': config, 'runs': statuses, 'rows': rows}, indent=2) + '\n')
+    with open(matrix_dir / 'results.csv', 'w', newline='') as f:
+        writer = csv.DictWriter(f, fieldnames=RESULT_COLUMNS)
+        writer.writeheader()
+        writer.writerows(rows)
+
+    failed = [status['run'] for status in statuses if status['status'] == 'failed']
+    print(f"✅ {len(statuses) - len(failed)}/{len(statuses)} run(s) completed, results in {matrix_dir}")
+    if failed:
+        print(f"❌ Failed: {', '.join(failed)}")
+        return 1
+    return 0
+
+
+def parse_device(spec):
+    """Split `adb`, `adb:<serial>`, or `ssh:<[user@]host>` into a transport, its target, and the platform to build for."""
+    transport, _, target = spec.partition(':')
+    if transport == 'adb':
+        return {'transport': 'adb', 'target': target or None, 'platform': 'android'}
+    if transport == 'ssh' and target:
+        return {'transport': 'ssh', 'target': target, 'platform': 'linux'}
+    sys.exit(f"Error: invalid device {spec!r}, expected adb, adb:<serial>, or ssh:<[user@]host>")
+
+
+def device_shell(device, command):
+    """Command line running a shell command on the device."""
+    if device['transport'] == 'adb':
+        return ['adb', *(['-s', device['target']] if device['target'] else []), 'shell', command]
+    return ['ssh', device['target'], command]
+
+
+def device_copy(device, source, destination, pull=False):
+    """Command line copying a file or directory to the device, or from it with `pull`
+    (a directory lands at `destination` itself when that does not exist yet)."""
+    if device['transport'] == 'adb':
+        return ['adb', *(['-s', device['target']] if device['target'] else []),
+                'pull' if pull else 'push', str(source), str(destination)]
+    if pull:
+        return ['scp', '-rq', f"{device['target']}:{source}", str(destination)]
+    return ['scp', '-rq', str(source), f"{device['target']}:{destination}"]
+
+
+def build_device_binaries(artifacts_dir, platform):
+    """Cross-compile the provers and device_runner for the platform into artifacts/device/<platform>."""
+    out_dir = artifacts_dir / 'device' / platform
+    image = f"zk-ecdsa-device-{platform}"
+    print(f"🔨 Cross-compiling the provers for {platform}/aarch64...")
+    if subprocess.run(['docker', 'build', '-f', str(REPO_ROOT / 'device' / 'Dockerfile'),
+                       '--build-arg', f"TARGET={platform}", '-t', image, str(REPO_ROOT)]).returncode != 0:
+        sys.exit(f"Error: could not build the {image} image")
+    shutil.rmtree(out_dir, ignore_errors=True)
+    out_dir.mkdir(parents=True)
+    if subprocess.run(['docker', 'run', '--rm', '-v', f"{out_dir}:/out", image]).returncode != 0:
+        sys.exit(f"Error: could not copy the binaries out of {image}")
+    return out_dir
+
+
+def stage_device_fixtures(stage_dir, stacks, artifacts_dir, ptau_hashes):
+    """Lay out each stack's keys and inputs under stage_dir as run-on-device.sh expects,
+    running the setup, witness generation, or gnark compilation on this machine first."""
+    for stack in stacks:
+        if not any((REPO_ROOT / stack / 'tests').glob('test_case_*.json')):
+            sys.exit(f"Error: no test cases in {stack}/tests; run generate_test_cases first")
+    setup, checksums = load_setup(artifacts_dir)
+
+    if 'rapidsnark' in stacks:
+        # circom witnesses need node or the x86-64 C++ generator, so only proving runs on the device
+        setup_stack(artifacts_dir, 'rapidsnark', setup, checksums, ptau_hashes)
+        cache_witnesses(artifacts_dir, 'rapidsnark', setup)
+        (stage_dir / 'rapidsnark' / 'witnesses').mkdir(parents=True)
+        for name in ['circuit.zkey', 'verification_key.json']:
+            shutil.copy(artifacts_dir / 'rapidsnark' / 'setup' / name, stage_dir / 'rapidsnark' / name)
+        for witness in (artifacts_dir / 'witnesses' / 'rapidsnark' / 'witnesses').glob('witness_*.wtns'):
+            shutil.copy(witness, stage_dir / 'rapidsnark' / 'witnesses')
+
+    if 'gnark' in stacks:
+        print("🔨 gnark: building the image and compiling the circuit...")
+        build_image('gnark')
+        keys_dir = artifacts_dir / 'device' / 'gnark'
+        shutil.rmtree(keys_dir, ignore_errors=True)
+        keys_dir.mkdir(parents=True)
+        docker_run('gnark', 'compile-circuit.sh', keys_dir)
+        (stage_dir / 'gnark' / 'keys').mkdir(parents=True)
+        (stage_dir / 'gnark' / 'tests').mkdir()
+        for name in ['circuit.r1cs', 'proving.key', 'verifying.key']:
+            shutil.copy(keys_dir / name, stage_dir / 'gnark' / 'keys' / name)
+        for test_case in [*(REPO_ROOT / 'gnark' / 'tests').glob('test_case_*.json'),
+                          *(REPO_ROOT / 'gnark' / 'tests').glob('manifest.json')]:
+            shutil.copy(test_case, stage_dir / 'gnark' / 'tests')
+
+
+def record_device_metadata(run_dir, device, stacks, binaries_dir):
+    """Write system_info.json for the device and each stack's benchmarks/metadata.json,
+    embedding it in the stack's other results files as the suites' collect-metadata.sh does."""
+    info = dict(line.split('=', 1) for line in (run_dir / 'device_info.txt').read_text().splitlines() if '=' in line)
+    info = {key: value or None for key, value in info.items()}
+    build = json.loads((binaries_dir / 'build.json').read_text())
+    git_commit = subprocess.run(['git', '-C', str(REPO_ROOT), 'rev-parse', 'HEAD'],
+                                capture_output=True, text=True).stdout.strip() or None
+    memory_mb = int(info['memory_total_mb']) if info.get('memory_total_mb') else None
+    os_name = f"Android {info['android_version']}" if info.get('android_version') else None
+    (run_dir / 'system_info.json').write_text(json.dumps({
+        'instance_type': info.get('model'),
+        'cpu_model': info.get('soc') or info.get('cpu_model'),
+        'cpu_cores': int(info['cpu_cores']) if info.get('cpu_cores') else None,
+        'memory_gb': memory_mb // 1024 if memory_mb else None,
+        'os': os_name,
+        'kernel': info.get('kernel'),
+        'arch': info.get('arch'),
+        'git_commit': git_commit,
+        'device': device['target'],
+        'transport': device['transport'],
+        'started_at': datetime.datetime.now(datetime.timezone.utc).strftime('%Y-%m-%dT%H:%M:%SZ'),
+    }, indent=2) + '\n')
+
+    manifest_path = REPO_ROOT / 'rapidsnark' / 'tests' / 'manifest.json'
+    batch_size = json.loads(manifest_path.read_text()).get('batch_size', 1) if manifest_path.is_file() else 1
+    for stack in stacks:
+        benchmarks_dir = run_dir / stack / 'benchmarks'
+        metadata = {
+            'cpu_model': info.get('soc') or info.get('cpu_model'),
+            'cpu_cores': int(info['cpu_cores']) if info.get('cpu_cores') else None,
+            'memory_total_mb': memory_mb,
+            'os': os_name,
+            'kernel': info.get('kernel'),
+            'arch': info.get('arch'),
+            'git_commit': git_commit,
+            'batch_size': batch_size,
+            'device_model': info.get('model'),
+            'platform': build['target'],
+            'witness_generator': 'host' if stack == 'rapidsnark' else None,
+            'tool_versions': {
+                'rapidsnark': build['rapidsnark'] if stack == 'rapidsnark' else None,
+                'go': build['go'] if stack == 'gnark' else None,
+            },
+            'collected_at': datetime.datetime.now(datetime.timezone.utc).strftime('%Y-%m-%dT%H:%M:%SZ'),
+        }
+        (benchmarks_dir / 'metadata.json').write_text(json.dumps(metadata, indent=2) + '\n')
+        for results_file in benchmarks_dir.glob('*.json'):
+            if results_file.name != 'metadata.json':
+                results = json.loads(results_file.read_text())
+                results_file.write_text(json.dumps({**results, 'metadata': metadata}, indent=2) + '\n')
+
+
+def cmd_device(args):
+    device = parse_device(args.device)
+    stacks = [stack for stack in DEVICE_STACKS if stack in args.stacks]
+    remote_dir = args.remote_dir or DEVICE_REMOTE_DIRS[device['transport']]
+    artifacts_dir = Path(args.artifacts_dir).resolve()
+
+    binaries_dir = build_device_binaries(artifacts_dir, device['platform'])
+    with tempfile.TemporaryDirectory() as stage:
+        stage_dir = Path(stage) / 'stage'
+        stage_dir.mkdir()
+        shutil.copytree(binaries_dir / 'bin', stage_dir / 'bin')
+        shutil.copytree(binaries_dir / 'lib', stage_dir / 'lib')
+        shutil.copy(REPO_ROOT / 'device' / 'run-on-device.sh', stage_dir)
+        stage_device_fixtures(stage_dir, stacks, artifacts_dir, ptau_hashes(args))
+
+        print(f"📤 Pushing binaries and fixtures to {args.device}:{remote_dir}...")
+        subprocess.run(device_shell(device, f"rm -rf {shlex.quote(remote_dir)}"), check=True)
+        if subprocess.run(device_copy(device, stage_dir, remote_dir)).returncode != 0:
+            sys.exit(f"Error: could not push to {args.device}")
+
+    env = f"BENCH_RUNS={args.runs} BENCH_WARMUP={args.warmup} VERIFY_RUNS={args.verify_runs}"
+    print(f"🚀 Running {', '.join(stacks)} on {args.device}...")
+    succeeded = subprocess.run(device_shell(
+        device, f"cd {shlex.quote(remote_dir)} && chmod +x bin/* run-on-device.sh && "
+                f"{env} ./run-on-device.sh {' '.join(stacks)}")).returncode == 0
+
+    run_dir = Path(args.output_dir).resolve() / \
+        f"device_{device['platform']}_{datetime.datetime.now().strftime('%Y%m%d_%H%M%S')}"
+    run_dir.parent.mkdir(parents=True, exist_ok=True)
+    if subprocess.run(device_copy(device, f"{remote_dir}/out", run_dir, pull=True)).returncode != 0:
+        sys.exit(f"Error: could not pull the results from {args.device}")
+    if not succeeded:
+        print(f"❌ The benchmark failed on {args.device}, partial results in {run_dir}")
+        return 1
+
+    record_device_metadata(run_dir, device, stacks, binaries_dir)
+    (run_dir / 'config.json').write_text(json.dumps({
+        'device': args.device,
+        'platform': device['platform'],
+        'stacks': stacks,
+        'runs': args.runs,
+        'warmup': args.warmup,
+        'verify_runs': args.verify_runs,
+    }, indent=2) + '\n')
+    summarize_run(run_dir)
+    print(f"✅ Results in {run_dir} (summary in {run_dir / 'summary'})")
+    return 0
+
+
+def add_ptau_blake2b_argument(parser):
+    parser.add_argument('--ptau-blake2b', type=ptau_blake2b_arg, action='append', default=[], metavar='N=HASH',
+                        help='published blake2b-512 of the 2^N Powers of Tau, for a power with no hash in '
+                             'bench.py (repeatable)')
+
+
+def main():
+    parser = argparse.ArgumentParser(description='Prepare and run the ECDSA benchmark suites.')
+    subparsers = parser.add_subparsers(dest='command', required=True)
+
+    setup = subparsers.add_parser(
+        'setup',
+        help='download the Powers of Tau and run the Groth16 setup for snarkjs/rapidsnark',
+        description='Compile each circom stack\'s circuit in its Docker image, download the smallest Hermez '
+                    'Powers of Tau that fits it (cached across runs), run the circuit-specific Groth16 setup, '
+                    'and record SHA-256 checksums of every file in the artifacts directory. A stack is skipped '
+                    'while its circuit, test manifest, and checksums are unchanged. The EC2 runner and the '
+                    'suites reuse the cached setup instead of running their own.')
+    setup.add_argument('--stacks', nargs='+', choices=GROTH16_STACKS, default=GROTH16_STACKS,
+                       help='stacks to set up (default: all)')
+    setup.add_argument('--artifacts-dir', default=DEFAULT_ARTIFACTS_DIR,
+                       help='where to cache downloads and setup output (default: artifacts/)')
+    setup.add_argument('--power', type=int, choices=range(PTAU_MIN_POWER, PTAU_MAX_POWER + 1), metavar='N',
+                       help='Powers of Tau size 2^N to use (default: the smallest that fits the circuit)')
+    add_ptau_blake2b_argument(setup)
+    setup.add_argument('--force', action='store_true', help='redo the setup even if the cached one is current')
+    setup.add_argument('--verify', action='store_true',
+                       help='only check the cached artifacts against their checksums (exits 1 on a mismatch)')
+    setup.set_defaults(func=cmd_setup)
+
+    witnesses = subparsers.add_parser(
+        'witnesses',
+        help='pre-generate and cache witnesses for snarkjs/rapidsnark',
+        description='Generate the witnesses of every test case in each circom stack\'s tests/ directory '
+                    'with its compute-witnesses.sh, timed with hyperfine as in a full run, and cache them '
+                    'with their timing in artifacts/witnesses/<stack>. Runs the setup first if needed. '
+                    'Suites with the cache mounted at /witnesses only time proving and report the cached '
+                    'witness timing. Witnesses are regenerated when the test cases or circuit change.')
+    witnesses.add_argument('--stacks', nargs='+', choices=GROTH16_STACKS, default=GROTH16_STACKS,
+                           help='stacks to generate witnesses for (default: all)')
+    witnesses.add_argument('--artifacts-dir', default=DEFAULT_ARTIFACTS_DIR,
+                           help='where the setup and witnesses are cached (default: artifacts/)')
+    witnesses.add_argument('--runs', type=int, default=1, help='timed runs per test case (default: 1)')
+    witnesses.add_argument('--warmup', type=int, help='untimed warmup runs per test case (default: 0)')
+    witnesses.add_argument('--force', action='store_true', help='regenerate even if the cached witnesses are current')
+    add_ptau_blake2b_argument(witnesses)
+    witnesses.set_defaults(func=cmd_witnesses)
+
+    run = subparsers.add_parser(
+        'run',
+        help='run the benchmark matrix declared in benchmarks.toml',
+        description='Expand the [matrix] of a benchmarks.toml into one run per combination of stack, curve, '
+                    'batch size, thread count, and test case count. Test cases are generated once per '
+                    'curve, batch size, and count, and snarkjs/rapidsnark reuse the setup cached by '
+                    '`setup` (which is refreshed when the circuit changes). Each run gets its own directory '
+                    'with a config.json and performance_data.json, and results.csv and results.json hold '
+                    'every metric tagged with its run\'s configuration. state.json records each run\'s status as '
+                    'it goes, so --resume can continue an interrupted matrix on the same test cases. '
+                    'Completed runs are skipped, and a failed or interrupted run continues where it stopped: '
+                    'the suites keep each test case\'s witness, proving, and verification timings and '
+                    'only time the test cases that are missing them.')
+    run.add_argument('--config', default=DEFAULT_CONFIG, help='matrix to run (default: benchmarks.toml)')
+    run.add_argument('--output-dir', help='where to write results (default: options.output_dir from the config)')
+    run.add_argument('--artifacts-dir', default=DEFAULT_ARTIFACTS_DIR,
+                     help='cached Powers of Tau and Groth16 setups (default: artifacts/)')
+    run.add_argument('--dry-run', action='store_true', help='only list the runs the matrix expands to')
+    run.add_argument('--resume', nargs='?', const='latest', metavar='MATRIX_DIR',
+                     help='continue an interrupted matrix (default: the latest in the output directory), '
+                          'skipping its completed runs and the test cases already timed in failed or interrupted ones')
+    add_ptau_blake2b_argument(run)
+    run.set_defaults(func=cmd_run)
+
+    device = subparsers.add_parser(
+        'device',
+        help='benchmark the native provers on an ARM phone (adb) or board (ssh)',
+        description='Cross-compile rapidsnark, the gnark benchmark executable, and device_runner (which '
+                    'stands in for hyperfine) for aarch64 with device/Dockerfile, push them to the device '
+                    'with the keys and test cases, time proving and verification there with '
+                    'device/run-on-device.sh, and pull the results back into '
+                    '<output-dir>/device_<platform>_<timestamp> in the same layout as the other suites, '
+                    'with a summary/performance_data.json. Setup, rapidsnark witness generation, and '
+                    'gnark circuit compilation run on this machine.')
+    device.add_argument('--device', required=True, metavar='DEVICE',
+                        help='adb (the only attached device), adb:<serial>, or ssh:<[user@]host>')
+    device.add_argument('--stacks', nargs='+', choices=DEVICE_STACKS, default=DEVICE_STACKS,
+                        help='stacks to run (default: all)')
+    device.add_argument('--remote-dir', help=f"working directory on the device (default: "
+                                             f"{DEVICE_REMOTE_DIRS['adb']} over adb, ~/{DEVICE_REMOTE_DIRS['ssh']} over ssh)")
+    device.add_argument('--runs', type=int, default=1, help='timed proving runs per test case (default: 1)')
+    device.add_argument('--warmup', type=int, default=0, help='untimed warmup runs per test case (default: 0)')
+    device.add_argument('--verify-runs', type=int, default=10, help='timed verifications per proof (default: 10)')
+    device.add_argument('--output-dir', default='results', help='where to write results (default: results/)')
+    device.add_argument('--artifacts-dir', default=DEFAULT_ARTIFACTS_DIR,
+                        help='cached setups and cross-compiled binaries (default: artifacts/)')
+    add_ptau_blake2b_argument(device)
+    device.set_defaults(func=cmd_device)
+
+    args = parser.parse_args()
+    sys.exit(args.func(args))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,79 @@
+// Browser side of the benchmark. Bundled into dist/prover.js by scripts/build.mjs
+// and driven by scripts/bench.mjs through window.harness.
+import * as snarkjs from 'snarkjs';
+import { Noir } from '@noir-lang/noir_js';
+import { UltraHonkBackend } from '@aztec/bb.js';
+import initACVM from '@noir-lang/acvm_js';
+import initNoirC from '@noir-lang/noirc_abi';
+
+async function fetchBytes(url) {
+  const response = await fetch(url);
+  if (!response.ok) {
+    throw new Error(`Failed to fetch ${url}: ${response.status}`);
+  }
+  return new Uint8Array(await response.arrayBuffer());
+}
+
+// Circuit artifacts are downloaded once in setup so that transfer time is
+// never counted as proving time
+async function setupSnarkjs() {
+  const wasm = await fetchBytes('/artifacts/snarkjs/circuit.wasm');
+  const zkey = await fetchBytes('/artifacts/snarkjs/circuit.zkey');
+  return {
+    async witness(input) {
+      const wtns = { type: 'mem' };
+      await snarkjs.wtns.calculate(input, { type: 'mem', data: wasm }, wtns);
+      return wtns;
+    },
+    async prove(wtns) {
+      await snarkjs.groth16.prove({ type: 'mem', data: zkey }, wtns);
+    },
+  };
+}
+
+async function setupNoir(threads) {
+  await Promise.all([
+    initACVM(fetch('/acvm_js_bg.wasm')),
+    initNoirC(fetch('/noirc_abi_wasm_bg.wasm')),
+  ]);
+  const circuit = await (await fetch('/artifacts/noir/benchmarking.json')).json();
+  const noir = new Noir(circuit);
+  const backend = new UltraHonkBackend(circuit.bytecode, { threads });
+  return {
+    async witness(input) {
+      const { witness } = await noir.execute(input);
+      return witness;
+    },
+    // keccak matches the native `bb prove --oracle_hash keccak` used for the EVM verifier
+    async prove(witness) {
+      await backend.generateProof(witness, { keccak: true });
+    },
+  };
+}
+
+let prover = null;
+
+window.harness = {
+  async setup(stack, threads) {
+    document.getElementById('status').textContent = `Setting up ${stack}...`;
+    prover = stack === 'noir' ? await setupNoir(threads) : await setupSnarkjs();
+    document.getElementById('status').textContent = `${stack} ready`;
+    return {
+      crossOriginIsolated: self.crossOriginIsolated,
+      hardwareConcurrency: navigator.hardwareConcurrency,
+    };
+  },
+
+  // Times witness generation and proving separately, in milliseconds
+  async run(input) {
+    const start = performance.now();
+    const witness = await prover.witness(input);
+    const witnessDone = performance.now();
+    await prover.prove(witness);
+    const proofDone = performance.now();
+    return { witness_ms: witnessDone - start, proof_ms: proofDone - witnessDone };
+  },
+};
+
+window.harnessReady = true;
+document.getElementById('status').textContent = 'Prover loaded';
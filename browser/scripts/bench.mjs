@@ -0,0 +1,203 @@
+// Serves the harness, circuit artifacts, and test cases, then drives headless
+// Chromium through every test case and records in-browser proving time and
+// peak memory in /out/benchmarks/browser_benchmark.json.
+import http from 'node:http';
+import { createReadStream, existsSync, mkdirSync, readdirSync, readFileSync, writeFileSync } from 'node:fs';
+import { extname, join } from 'node:path';
+import puppeteer from 'puppeteer-core';
+import { parse as parseToml } from 'smol-toml';
+
+const BENCH_RUNS = parseInt(process.env.BENCH_RUNS || '1', 10);
+// One untimed run by default: bb.js downloads its SRS and both provers compile
+// their wasm on first use
+const BENCH_WARMUP = parseInt(process.env.BENCH_WARMUP || '1', 10);
+const BROWSER_STACKS = (process.env.BROWSER_STACKS || 'snarkjs noir').split(/\s+/).filter(Boolean);
+const BROWSER_THREADS = parseInt(process.env.BROWSER_THREADS || '0', 10);
+const BROWSER_TIMEOUT_MS = parseInt(process.env.BROWSER_TIMEOUT_MS || String(60 * 60 * 1000), 10);
+const CHROMIUM_PATH = process.env.PUPPETEER_EXECUTABLE_PATH || '/usr/bin/chromium';
+const PORT = parseInt(process.env.HARNESS_PORT || '8080', 10);
+
+// Artifacts produced by the native snarkjs and Noir suites, mounted under /in
+const ROUTES = {
+  '/artifacts/snarkjs/circuit.wasm': '/in/snarkjs/setup/circuit_js/circuit.wasm',
+  '/artifacts/snarkjs/circuit.zkey': '/in/snarkjs/setup/circuit.zkey',
+  '/artifacts/noir/benchmarking.json': '/in/noir/compilation/benchmarking.json',
+};
+
+const CONTENT_TYPES = {
+  '.html': 'text/html',
+  '.js': 'text/javascript',
+  '.json': 'application/json',
+  '.wasm': 'application/wasm',
+};
+
+const TEST_CASES = {
+  snarkjs: { dir: 'tests/snarkjs', extension: '.json', parse: (text) => JSON.parse(text) },
+  noir: { dir: 'tests/noir', extension: '.toml', parse: (text) => parseToml(text) },
+};
+
+function serve() {
+  const server = http.createServer((req, res) => {
+    const path = new URL(req.url, 'http://localhost').pathname;
+    const file = ROUTES[path] || join('dist', path === '/' ? 'index.html' : path.replace(/^\/+/, ''));
+    if (file.includes('..') || !existsSync(file)) {
+      res.writeHead(404);
+      res.end();
+      return;
+    }
+    // Cross-origin isolation enables SharedArrayBuffer, which both provers
+    // need for multithreaded wasm
+    res.writeHead(200, {
+      'Content-Type': CONTENT_TYPES[extname(file)] || 'application/octet-stream',
+      'Cross-Origin-Opener-Policy': 'same-origin',
+      'Cross-Origin-Embedder-Policy': 'require-corp',
+    });
+    createReadStream(file).pipe(res);
+  });
+  return new Promise((resolve) => server.listen(PORT, () => resolve(server)));
+}
+
+function listTestCases(stack) {
+  const { dir, extension } = TEST_CASES[stack];
+  if (!existsSync(dir)) {
+    return [];
+  }
+  return readdirSync(dir)
+    .filter((name) => name.startsWith('test_case_') && name.endsWith(extension))
+    .map((name) => name.slice(0, -extension.length))
+    .sort((a, b) => parseInt(a.split('_').pop(), 10) - parseInt(b.split('_').pop(), 10));
+}
+
+// Peak resident memory (VmHWM) of the largest process in the browser's tree.
+// Pages and their dedicated workers share one renderer process, so this is
+// the renderer that did the proving.
+function peakRssBytes(rootPid) {
+  const children = new Map();
+  for (const entry of readdirSync('/proc')) {
+    if (!/^\d+$/.test(entry)) continue;
+    try {
+      const stat = readFileSync(`/proc/${entry}/stat`, 'utf8');
+      const ppid = parseInt(stat.slice(stat.lastIndexOf(')') + 2).split(' ')[1], 10);
+      if (!children.has(ppid)) children.set(ppid, []);
+      children.get(ppid).push(parseInt(entry, 10));
+    } catch {
+      // Process exited while scanning
+    }
+  }
+
+  let peak = 0;
+  const pending = [rootPid];
+  while (pending.length > 0) {
+    const pid = pending.pop();
+    try {
+      const match = readFileSync(`/proc/${pid}/status`, 'utf8').match(/^VmHWM:\s+(\d+) kB/m);
+      if (match) peak = Math.max(peak, parseInt(match[1], 10) * 1024);
+    } catch {
+      // Process exited while scanning
+    }
+    pending.push(...(children.get(pid) || []));
+  }
+  return peak || null;
+}
+
+function summarize(times) {
+  if (times.length === 0) {
+    return { mean: null, median: null, min: null, max: null };
+  }
+  const sorted = [...times].sort((a, b) => a - b);
+  const mid = Math.floor(sorted.length / 2);
+  return {
+    mean: times.reduce((sum, t) => sum + t, 0) / times.length,
+    median: sorted.length % 2 === 1 ? sorted[mid] : (sorted[mid - 1] + sorted[mid]) / 2,
+    min: sorted[0],
+    max: sorted[sorted.length - 1],
+  };
+}
+
+// Each test case gets a fresh browser so its peak memory is measured in isolation
+async function benchmarkTestCase(stack, testCase) {
+  const { dir, extension, parse } = TEST_CASES[stack];
+  const input = parse(readFileSync(join(dir, testCase + extension), 'utf8'));
+
+  const browser = await puppeteer.launch({
+    executablePath: CHROMIUM_PATH,
+    headless: true,
+    protocolTimeout: BROWSER_TIMEOUT_MS,
+    args: ['--no-sandbox', '--disable-dev-shm-usage'],
+  });
+  const result = { stack, test_case: testCase, witness_times: [], times: [] };
+  try {
+    const page = await browser.newPage();
+    page.setDefaultTimeout(BROWSER_TIMEOUT_MS);
+    page.on('pageerror', (error) => console.error(`   [${stack}] page error: ${error.message}`));
+    await page.goto(`http://localhost:${PORT}/`);
+    await page.waitForFunction(() => window.harnessReady === true);
+
+    const threads = BROWSER_THREADS || undefined;
+    const env = await page.evaluate((s, t) => window.harness.setup(s, t), stack, threads);
+    result.cross_origin_isolated = env.crossOriginIsolated;
+    result.hardware_concurrency = env.hardwareConcurrency;
+
+    for (let i = 0; i < BENCH_WARMUP; i++) {
+      await page.evaluate((data) => window.harness.run(data), input);
+    }
+    for (let i = 0; i < BENCH_RUNS; i++) {
+      const { witness_ms, proof_ms } = await page.evaluate((data) => window.harness.run(data), input);
+      result.witness_times.push(witness_ms / 1000);
+      result.times.push(proof_ms / 1000);
+    }
+    result.peak_rss_bytes = peakRssBytes(browser.process().pid);
+    result.error = null;
+  } catch (error) {
+    // Out-of-memory and wasm limits are expected on large circuits; record and move on
+    result.peak_rss_bytes = peakRssBytes(browser.process().pid);
+    result.error = error.message;
+  } finally {
+    await browser.close();
+  }
+  return { ...result, ...summarize(result.times) };
+}
+
+const server = await serve();
+mkdirSync('/out/benchmarks', { recursive: true });
+
+const versionBrowser = await puppeteer.launch({ executablePath: CHROMIUM_PATH, headless: true, args: ['--no-sandbox'] });
+const browserVersion = await versionBrowser.version();
+await versionBrowser.close();
+
+console.log(`🌐 ${browserVersion}: ${BENCH_RUNS} runs per test case (warmup: ${BENCH_WARMUP})`);
+
+const results = [];
+for (const stack of BROWSER_STACKS) {
+  if (!TEST_CASES[stack]) {
+    console.error(`❌ Unknown browser stack: ${stack}`);
+    process.exit(1);
+  }
+  const testCases = listTestCases(stack);
+  if (testCases.length === 0) {
+    console.error(`⚠️  No ${stack} test cases found in ${TEST_CASES[stack].dir}, skipping`);
+    continue;
+  }
+  for (const testCase of testCases) {
+    console.log(`🔐 [${stack}] Proving ${testCase} in the browser...`);
+    const result = await benchmarkTestCase(stack, testCase);
+    if (result.error) {
+      console.log(`   ❌ ${result.error}`);
+    } else {
+      const peakMb = result.peak_rss_bytes ? (result.peak_rss_bytes / 1024 / 1024).toFixed(0) : '?';
+      console.log(`   ✅ proof ${result.mean.toFixed(3)}s (witness ${summarize(result.witness_times).mean.toFixed(3)}s), peak ${peakMb} MB`);
+    }
+    results.push(result);
+  }
+}
+
+server.close();
+
+writeFileSync('/out/benchmarks/browser_benchmark.json', JSON.stringify({
+  browser: browserVersion,
+  runs: BENCH_RUNS,
+  warmup: BENCH_WARMUP,
+  results,
+}, null, 2));
+
+console.log('✅ Browser results saved to /out/benchmarks/browser_benchmark.json');
@@ -0,0 +1,24 @@
+// Bundles the browser harness into dist/ together with the wasm modules that
+// noir_js loads at runtime.
+import { build } from 'esbuild';
+import { copyFileSync, mkdirSync } from 'node:fs';
+
+mkdirSync('dist', { recursive: true });
+
+await build({
+  entryPoints: ['harness/prover.js'],
+  bundle: true,
+  format: 'esm',
+  platform: 'browser',
+  target: 'es2022',
+  outfile: 'dist/prover.js',
+  // Node built-ins referenced by snarkjs' dependencies are never reached in the browser
+  external: ['fs', 'os', 'crypto', 'path', 'url', 'worker_threads'],
+  logLevel: 'info',
+});
+
+copyFileSync('harness/index.html', 'dist/index.html');
+copyFileSync('node_modules/@noir-lang/acvm_js/web/acvm_js_bg.wasm', 'dist/acvm_js_bg.wasm');
+copyFileSync('node_modules/@noir-lang/noirc_abi/web/noirc_abi_wasm_bg.wasm', 'dist/noirc_abi_wasm_bg.wasm');
+
+console.log('✅ Harness built in dist/');
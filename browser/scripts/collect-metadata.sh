@@ -0,0 +1,73 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+echo "🖥️  Recording hardware and toolchain metadata..."
+
+mkdir -p /out/benchmarks
+
+# First line of a tool's version output, or nothing if the tool is missing
+tool_version() {
+    "$@" 2>/dev/null | head -n1 || true
+}
+
+# Version of a package installed in the harness's node_modules
+npm_version() {
+    npm ls "$1" --depth=0 --json 2>/dev/null | jq -r --arg pkg "$1" '.dependencies[$pkg].version // empty'
+}
+
+# CPU model from lscpu, falling back to /proc/cpuinfo
+CPU_MODEL=$(lscpu 2>/dev/null | awk -F: '/^Model name/ { sub(/^[ \t]+/, "", $2); print $2; exit }')
+if [ -z "$CPU_MODEL" ]; then
+    CPU_MODEL=$(awk -F: '/^model name/ { sub(/^[ \t]+/, "", $2); print $2; exit }' /proc/cpuinfo)
+fi
+MEMORY_TOTAL_MB=$(awk '/^MemTotal/ { print int($2 / 1024) }' /proc/meminfo)
+OS_NAME=$(. /etc/os-release 2>/dev/null && echo "$PRETTY_NAME")
+
+# GIT_COMMIT is passed in by the benchmark runner; empty values are recorded as null
+jq -n \
+    --arg cpu_model "$CPU_MODEL" \
+    --argjson cpu_cores "$(nproc)" \
+    --argjson memory_total_mb "${MEMORY_TOTAL_MB:-null}" \
+    --arg os "$OS_NAME" \
+    --arg kernel "$(uname -r)" \
+    --arg arch "$(uname -m)" \
+    --arg git_commit "${GIT_COMMIT:-}" \
+    --arg node "$(tool_version node --version)" \
+    --arg chromium "$(tool_version chromium --version)" \
+    --arg snarkjs "$(npm_version snarkjs)" \
+    --arg noir_js "$(npm_version @noir-lang/noir_js)" \
+    --arg bb_js "$(npm_version @aztec/bb.js)" \
+    '{
+        cpu_model: $cpu_model,
+        cpu_cores: $cpu_cores,
+        memory_total_mb: $memory_total_mb,
+        os: $os,
+        kernel: $kernel,
+        arch: $arch,
+        git_commit: $git_commit,
+        tool_versions: {
+            node: $node,
+            chromium: $chromium,
+            snarkjs: $snarkjs,
+            noir_js: $noir_js,
+            bb_js: $bb_js
+        },
+        collected_at: (now | todate)
+    } | walk(if . == "" then null else . end)' > /out/benchmarks/metadata.json
+
+# Embed the metadata block in every results file so each one stands on its own
+for results_file in /out/benchmarks/*.json; do
+    if [ "$results_file" = "/out/benchmarks/metadata.json" ]; then
+        continue
+    fi
+    jq --slurpfile metadata /out/benchmarks/metadata.json \
+        'if type == "object" then . + {metadata: $metadata[0]} else . end' \
+        "$results_file" > "$results_file.tmp"
+    mv "$results_file.tmp" "$results_file"
+done
+
+echo "✅ Metadata saved to /out/benchmarks/metadata.json"
+jq -r '"   \(.cpu_model) (\(.cpu_cores) cores, \(.memory_total_mb) MB), \(.os)"' /out/benchmarks/metadata.json
+jq -r '.tool_versions | to_entries[] | "   \(.key): \(.value // "not found")"' /out/benchmarks/metadata.json
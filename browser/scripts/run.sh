@@ -0,0 +1,37 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+echo "🚀 Starting in-browser proving benchmark..."
+
+# The browser suite reuses the circuits built by the native snarkjs and Noir suites
+if [[ " ${BROWSER_STACKS:-snarkjs noir} " =~ " snarkjs " ]] && [ ! -f "/in/snarkjs/setup/circuit.zkey" ]; then
+    echo "❌ snarkjs artifacts not found!"
+    echo "   Mount the snarkjs suite's output directory at /in/snarkjs."
+    exit 1
+fi
+if [[ " ${BROWSER_STACKS:-snarkjs noir} " =~ " noir " ]] && [ ! -f "/in/noir/compilation/benchmarking.json" ]; then
+    echo "❌ Noir artifacts not found!"
+    echo "   Mount the Noir suite's output directory at /in/noir."
+    exit 1
+fi
+
+# Prove every test case in headless Chromium
+echo "🌐 Proving in the browser..."
+node scripts/bench.mjs
+
+# Record hardware and toolchain versions alongside the results
+echo "🖥️  Collecting environment metadata..."
+./scripts/collect-metadata.sh
+
+echo ""
+echo "📈 Browser Proving Summary:"
+echo "----------------------------------------"
+jq -r '.results | group_by(.stack)[] |
+    (map(select(.error == null)) | [.[].times[]]) as $times |
+    "\(.[0].stack): \(if ($times | length) > 0 then "\($times | add / length * 1000 | round / 1000)s mean" else "no successful proofs" end), peak \(([.[].peak_rss_bytes | select(. != null)] | max // 0) / 1048576 | round) MB, \(map(select(.error != null)) | length) failed"' \
+    /out/benchmarks/browser_benchmark.json
+echo "----------------------------------------"
+
+echo "✅ All done! Check the benchmarks directory for results."
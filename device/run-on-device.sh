@@ -0,0 +1,80 @@
+#!/bin/sh
+
+# Runs on the device: times proving and verification for each stack pushed by
+# `bench.py device`, with device_runner standing in for hyperfine. Written for
+# a plain POSIX shell, since Android's has no bash or jq.
+#
+# Usage: run-on-device.sh <stack>...
+
+# Exit on error
+set -e
+
+cd "$(dirname "$0")"
+ROOT=$(pwd)
+
+# Timed runs and untimed warmup runs per test case (set by bench.py)
+BENCH_RUNS=${BENCH_RUNS:-1}
+BENCH_WARMUP=${BENCH_WARMUP:-0}
+VERIFY_RUNS=${VERIFY_RUNS:-10}
+
+RUNNER="$ROOT/bin/device_runner"
+# rapidsnark's Android build links shared libraries shipped next to it
+export LD_LIBRARY_PATH="$ROOT/lib${LD_LIBRARY_PATH:+:$LD_LIBRARY_PATH}"
+
+# Comma-separated, numerically sorted test case numbers from files named <prefix>N<suffix>
+test_case_list() {
+    ls "$1" | sed -n "s/^$2\([0-9]*\)$3\$/\1/p" | sort -n | tr '\n' ',' | sed 's/,$//'
+}
+
+run_rapidsnark() {
+    cd "$ROOT/rapidsnark"
+    mkdir -p proofs "$ROOT/out/rapidsnark/benchmarks"
+    TEST_CASES=$(test_case_list witnesses 'witness_' '\.wtns')
+    echo "🔐 rapidsnark: proving test cases $TEST_CASES..."
+    "$RUNNER" --warmup "$BENCH_WARMUP" --runs "$BENCH_RUNS" -L test_case "$TEST_CASES" \
+        --export-json "$ROOT/out/rapidsnark/benchmarks/all_proofs_benchmark.json" \
+        "$ROOT/bin/prover circuit.zkey witnesses/witness_{test_case}.wtns proofs/proof_{test_case}.json proofs/public_{test_case}.json"
+    echo "🔍 rapidsnark: verifying proofs..."
+    "$RUNNER" --warmup "$BENCH_WARMUP" --runs "$VERIFY_RUNS" -L test_case "$TEST_CASES" \
+        --export-json "$ROOT/out/rapidsnark/benchmarks/all_verifications_benchmark.json" \
+        "$ROOT/bin/verifier verification_key.json proofs/public_{test_case}.json proofs/proof_{test_case}.json"
+}
+
+run_gnark() {
+    cd "$ROOT/gnark"
+    mkdir -p "$ROOT/out/gnark/benchmarks"
+    TEST_CASES=$(test_case_list tests 'test_case_' '\.json')
+    echo "🔐 gnark: proving test cases $TEST_CASES..."
+    "$RUNNER" --warmup "$BENCH_WARMUP" --runs "$BENCH_RUNS" -L test_case "$TEST_CASES" \
+        --export-json "$ROOT/out/gnark/benchmarks/all_proofs_benchmark.json" \
+        "$ROOT/bin/gnark-bench prove -d keys tests/test_case_{test_case}.json"
+    echo "🔍 gnark: verifying proofs..."
+    "$RUNNER" --warmup "$BENCH_WARMUP" --runs "$VERIFY_RUNS" -L test_case "$TEST_CASES" \
+        --export-json "$ROOT/out/gnark/benchmarks/all_verifications_benchmark.json" \
+        "$ROOT/bin/gnark-bench verify -d keys tests/test_case_{test_case}.json"
+}
+
+rm -rf "$ROOT/out"
+mkdir -p "$ROOT/out"
+
+# Hardware the results were measured on, as key=value lines for bench.py
+{
+    echo "model=$(getprop ro.product.model 2>/dev/null || cat /sys/firmware/devicetree/base/model 2>/dev/null | tr -d '\0' || true)"
+    echo "android_version=$(getprop ro.build.version.release 2>/dev/null || true)"
+    echo "soc=$(getprop ro.soc.model 2>/dev/null || true)"
+    echo "cpu_model=$(sed -n 's/^\(Hardware\|model name\)[[:space:]]*: *//p' /proc/cpuinfo | head -n1)"
+    echo "cpu_cores=$(grep -c '^processor' /proc/cpuinfo)"
+    echo "memory_total_mb=$(awk '/^MemTotal/ { print int($2 / 1024) }' /proc/meminfo)"
+    echo "kernel=$(uname -r)"
+    echo "arch=$(uname -m)"
+} > "$ROOT/out/device_info.txt"
+
+for stack in "$@"; do
+    case "$stack" in
+        rapidsnark) run_rapidsnark ;;
+        gnark) run_gnark ;;
+        *) echo "❌ Unknown stack: $stack"; exit 1 ;;
+    esac
+done
+
+echo "✅ Results saved to $ROOT/out"
@@ -0,0 +1,428 @@
+#!/usr/bin/env python3
+"""Work with performance_data.json files produced by summarize-results.sh.
+
+Subcommands:
+    compare BASELINE CURRENT   Report per-metric deltas and fail on regressions
+    report RESULTS...          Render per-stack tables and SVG bar charts as Markdown or HTML
+"""
+import argparse
+import html
+import json
+import sys
+from pathlib import Path
+
+RESULTS_FILE = 'performance_data.json'
+
+TIMING_PHASES = ['witnesses', 'proofs', 'verifications']
+
+# Report columns: (heading, (phase, metric) from flatten_metrics, scale, format)
+REPORT_METRICS = [
+    ('Witness (s)', ('witnesses', 'mean'), 1, '.3f'),
+    ('Proving (s)', ('proofs', 'mean'), 1, '.3f'),
+    ('Witness + proving (s)', ('end_to_end', 'mean'), 1, '.3f'),
+    ('Proving median (s)', ('proofs', 'median'), 1, '.3f'),
+    ('Proving per signature (s)', ('per_signature', 'proving_time'), 1, '.3f'),
+    ('Verification (ms)', ('verifications', 'mean'), 1000, '.2f'),
+    ('Browser proving (s)', ('browser', 'mean'), 1, '.3f'),
+    ('Browser peak memory (MB)', ('browser', 'peak_rss_bytes'), 1 / (1024 * 1024), ',.0f'),
+    ('Proof size (bytes)', ('artifacts', 'proof_bytes'), 1, ',.0f'),
+    ('Gas', ('on_chain_verification', 'gas'), 1, ',.0f'),
+    ('Gas per signature', ('per_signature', 'gas'), 1, ',.0f'),
+    ('Transaction gas', ('on_chain_verification', 'transaction_gas'), 1, ',.0f'),
+    ('Calldata (bytes)', ('on_chain_verification', 'calldata_bytes'), 1, ',.0f'),
+]
+
+# Report charts: (file name, title, heading of the REPORT_METRICS column to plot)
+REPORT_CHARTS = [
+    ('proving_time', 'Proving time (s)', 'Proving (s)'),
+    ('verification_time', 'Verification time (ms)', 'Verification (ms)'),
+    ('browser_peak_memory', 'Browser peak memory (MB)', 'Browser peak memory (MB)'),
+    ('proof_size', 'Proof size (bytes)', 'Proof size (bytes)'),
+    ('gas', 'Verification gas', 'Gas'),
+    ('transaction_gas', 'Verification transaction gas', 'Transaction gas'),
+]
+
+CHART_COLORS = ['#1f77b4', '#2ca02c', '#d62728', '#9467bd', '#ff7f0e', '#8c564b', '#e377c2', '#17becf']
+
+
+def load_results(path):
+    """Load a performance_data.json file, or the one inside a results directory."""
+    path = Path(path)
+    if path.is_dir():
+        path = path / RESULTS_FILE
+    try:
+        with open(path) as f:
+            return json.load(f)
+    except FileNotFoundError:
+        sys.exit(f"Error: {path} not found")
+    except json.JSONDecodeError:
+        sys.exit(f"Error: Invalid JSON in {path}")
+
+
+def flatten_metrics(data):
+    """Map every comparable number in a results file to its (stack, phase, metric) key."""
+    metrics = {}
+    timing_stats = data.get('timing_stats', {})
+    for suite, phases in timing_stats.items():
+        for phase in TIMING_PHASES:
+            stats = phases.get(phase)
+            if stats:
+                metrics[(suite, phase, 'mean')] = stats['mean']
+                metrics[(suite, phase, 'median')] = stats['median']
+
+    # Older results predate timing_stats and only have per-suite means
+    for suite, value in data.get('witness_times', {}).items():
+        metrics.setdefault((suite, 'witnesses', 'mean'), value)
+    for suite, value in data.get('proving_times', {}).items():
+        metrics.setdefault((suite, 'proofs', 'mean'), value)
+    for suite, value in data.get('verification_times', {}).items():
+        metrics.setdefault((suite, 'verifications', 'mean'), value)
+    for suite, value in data.get('verification_medians', {}).items():
+        metrics.setdefault((suite, 'verifications', 'median'), value)
+
+    # What a prover actually waits for when the witness isn't precomputed
+    for suite in {suite for suite, phase, _ in metrics if phase == 'witnesses'}:
+        if (suite, 'proofs', 'mean') in metrics:
+            metrics[(suite, 'end_to_end', 'mean')] = metrics[(suite, 'witnesses', 'mean')] + metrics[(suite, 'proofs', 'mean')]
+
+    for suite, points in data.get('thread_scaling', {}).items():
+        for point in points:
+            metrics[(suite, f"proofs_{point['threads']}_threads", 'mean')] = point['mean']
+
+    for suite, result in data.get('browser_proving', {}).items():
+        for key in ['mean', 'median', 'witness_mean', 'peak_rss_bytes']:
+            if result.get(key) is not None:
+                metrics[(suite, 'browser', key)] = result[key]
+
+    for suite, value in data.get('gas_costs', {}).items():
+        metrics[(suite, 'on_chain_verification', 'gas')] = value
+
+    # Whole verification transactions against a verifier deployed on a local chain
+    for suite, onchain in data.get('onchain_verification', {}).items():
+        for key, metric in [('gas_used', 'transaction_gas'), ('calldata_bytes', 'calldata_bytes'),
+                            ('deployment_gas', 'deployment_gas'), ('bytecode_bytes', 'bytecode_bytes')]:
+            if onchain.get(key) is not None:
+                metrics[(suite, 'on_chain_verification', metric)] = onchain[key]
+
+    # Batched runs (--batch-size) prove several signatures at once; single-signature
+    # runs would only repeat the totals
+    for suite, amortized in data.get('amortized', {}).items():
+        if amortized.get('batch_size', 1) > 1:
+            for key in ['proving_time', 'verification_time', 'gas', 'onchain_gas']:
+                if amortized.get(key) is not None:
+                    metrics[(suite, 'per_signature', key)] = amortized[key]
+
+    for suite, sizes in data.get('artifact_sizes', {}).items():
+        for key, value in sizes.items():
+            if value is not None:
+                metrics[(suite, 'artifacts', key)] = value
+
+    return metrics
+
+
+def compare_results(baseline, current, threshold):
+    """Compare two results files metric by metric.
+
+    Every metric is a cost (time, memory, gas, or bytes), so growth beyond
+    `threshold` percent is a regression. Returns rows of
+    (key, baseline, current, delta_percent, status), where status is
+    'regression', 'improvement', 'unchanged', 'missing', or 'new'.
+    """
+    baseline_metrics = flatten_metrics(baseline)
+    current_metrics = flatten_metrics(current)
+    rows = []
+    for key in sorted(set(baseline_metrics) | set(current_metrics)):
+        old = baseline_metrics.get(key)
+        new = current_metrics.get(key)
+        if new is None:
+            rows.append((key, old, None, None, 'missing'))
+        elif old is None:
+            rows.append((key, None, new, None, 'new'))
+        else:
+            delta = (new - old) / old * 100 if old else (0.0 if new == old else float('inf'))
+            if delta > threshold:
+                status = 'regression'
+            elif delta < -threshold:
+                status = 'improvement'
+            else:
+                status = 'unchanged'
+            rows.append((key, old, new, delta, status))
+    return rows
+
+
+def format_value(value):
+    if value is None:
+        return '-'
+    if isinstance(value, int) or float(value).is_integer():
+        return f"{int(value):,}"
+    return f"{value:.4g}"
+
+
+def print_comparison(rows, threshold, label=None):
+    """Print a comparison table and return the number of regressions."""
+    markers = {'regression': '❌', 'improvement': '✅', 'unchanged': '', 'missing': '⚠️ missing', 'new': 'new'}
+    header = ('Stack', 'Phase', 'Metric', 'Baseline', 'Current', 'Delta', '')
+    table = [header]
+    for (stack, phase, metric), old, new, delta, status in rows:
+        delta_text = f"{delta:+.1f}%" if delta is not None else '-'
+        table.append((stack, phase, metric, format_value(old), format_value(new), delta_text, markers[status]))
+
+    widths = [max(len(row[i]) for row in table) for i in range(len(header))]
+    if label:
+        print(f"\n=== {label} ===")
+    for row in table:
+        print('  '.join(cell.ljust(width) for cell, width in zip(row, widths)).rstrip())
+
+    regressions = [row for row in rows if row[4] == 'regression']
+    missing = [row for row in rows if row[4] == 'missing']
+    print(f"\n{len(regressions)} regression(s) beyond {threshold:g}%, "
+          f"{sum(1 for row in rows if row[4] == 'improvement')} improvement(s), "
+          f"{len(missing)} metric(s) missing from the current run")
+    return len(regressions)
+
+
+def result_pairs(baseline_path, current_path):
+    """Pair up the results to compare.
+
+    Two files (or two single-instance directories) give one pair. Two
+    collected_results_* directories are paired by instance subdirectory.
+    """
+    baseline_path, current_path = Path(baseline_path), Path(current_path)
+    instance_dirs = lambda path: {d.name: d for d in path.iterdir() if (d / RESULTS_FILE).is_file()} if path.is_dir() else {}
+    baseline_instances = instance_dirs(baseline_path)
+    current_instances = instance_dirs(current_path)
+    if baseline_instances and current_instances:
+        shared = sorted(set(baseline_instances) & set(current_instances))
+        for name in sorted(set(baseline_instances) ^ set(current_instances)):
+            print(f"Warning: {name} is only in one of the result sets, skipping")
+        return [(name, baseline_instances[name], current_instances[name]) for name in shared]
+    if current_instances:
+        # One baseline file compared against every instance of a collected run
+        return [(name, baseline_path, path) for name, path in sorted(current_instances.items())]
+    return [(None, baseline_path, current_path)]
+
+
+def cmd_compare(args):
+    pairs = result_pairs(args.baseline, args.current)
+    if not pairs:
+        sys.exit("Error: no instance results in common between baseline and current")
+
+    total_regressions = 0
+    for label, baseline_path, current_path in pairs:
+        rows = compare_results(load_results(baseline_path), load_results(current_path), args.threshold)
+        total_regressions += print_comparison(rows, args.threshold, label)
+
+    if total_regressions > 0:
+        print(f"\n❌ {total_regressions} metric(s) regressed by more than {args.threshold:g}%")
+        return 1
+    print(f"\n✅ No metric regressed by more than {args.threshold:g}%")
+    return 0
+
+
+def report_runs(paths):
+    """Load every results file to report on as (label, data).
+
+    A collected_results_* directory expands to one run per instance.
+    """
+    runs = []
+    for path in map(Path, paths):
+        instances = sorted(d for d in path.iterdir() if (d / RESULTS_FILE).is_file()) if path.is_dir() else []
+        for results_path in instances or [path]:
+            data = load_results(results_path)
+            # Matrix runs (bench.py run) are labelled by their configuration
+            label = data.get('config', {}).get('run') or data.get('instance_type')
+            if not label or label == 'unknown':
+                label = (results_path if results_path.is_dir() else results_path.parent).name
+            runs.append((label, data))
+
+    # Keep labels unique when the same instance type appears in several result sets
+    labels = [label for label, _ in runs]
+    return [(f"{label} ({labels[:i].count(label) + 1})" if labels.count(label) > 1 else label, data)
+            for i, (label, data) in enumerate(runs)]
+
+
+def svg_bar_chart(title, groups, series):
+    """Grouped bar chart with one group per stack and one bar per run."""
+    bar_width, group_gap, plot_height = 22, 30, 200
+    top, left, bottom = 50, 20, 40
+    group_width = len(series) * bar_width + group_gap
+    width = max(480, left * 2 + len(groups) * group_width, left * 2 + len(series) * 120)
+    height = top + plot_height + bottom
+    peak = max((value for _, values in series for value in values if value is not None), default=0) or 1
+
+    parts = [f'<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" '
+             f'viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">',
+             f'<text x="{width / 2}" y="18" text-anchor="middle" font-size="14" font-weight="bold">{html.escape(title)}</text>',
+             f'<line x1="{left}" y1="{top + plot_height}" x2="{width - left}" y2="{top + plot_height}" stroke="#333"/>']
+    if len(series) > 1:
+        for i, (label, _) in enumerate(series):
+            x = left + i * 120
+            parts.append(f'<rect x="{x}" y="28" width="10" height="10" fill="{CHART_COLORS[i % len(CHART_COLORS)]}"/>')
+            parts.append(f'<text x="{x + 14}" y="37">{html.escape(label)}</text>')
+    for g, group in enumerate(groups):
+        x0 = left + g * group_width + group_gap / 2
+        for i, (_, values) in enumerate(series):
+            value = values[g]
+            if value is None:
+                continue
+            bar_height = value / peak * plot_height
+            x = x0 + i * bar_width
+            y = top + plot_height - bar_height
+            parts.append(f'<rect x="{x:.1f}" y="{y:.1f}" width="{bar_width - 4}" height="{bar_height:.1f}" '
+                         f'fill="{CHART_COLORS[i % len(CHART_COLORS)]}"><title>{value:,.4g}</title></rect>')
+            parts.append(f'<text x="{x + (bar_width - 4) / 2:.1f}" y="{y - 3:.1f}" text-anchor="middle" font-size="9">{value:,.3g}</text>')
+        parts.append(f'<text x="{x0 + len(series) * bar_width / 2:.1f}" y="{top + plot_height + 16}" '
+                     f'text-anchor="middle">{html.escape(group)}</text>')
+    parts.append('</svg>')
+    return '\n'.join(parts)
+
+
+def build_report(runs):
+    """Lay out the report as blocks shared by the Markdown and HTML renderers."""
+    metrics = [(label, flatten_metrics(data)) for label, data in runs]
+    stacks = sorted({stack for _, run_metrics in metrics for stack, _, _ in run_metrics})
+    value = lambda run_metrics, stack, column: (
+        run_metrics[(stack, *column[1])] * column[2] if (stack, *column[1]) in run_metrics else None)
+
+    blocks = [('heading', 1, 'ZK-SNARK ECDSA Benchmark Report')]
+
+    blocks.append(('heading', 2, 'Runs'))
+    overview = []
+    for label, data in runs:
+        host = data.get('metadata', {}).get('host', {})
+        overview.append([label, data.get('instance_type', 'Unknown'), str(data.get('cpu_cores', 'N/A')),
+                         f"{data.get('memory_gb', 'N/A')}GB", data.get('timestamp', 'N/A'),
+                         (host.get('git_commit') or 'N/A')[:12]])
+    blocks.append(('table', ['Run', 'Instance', 'CPU Cores', 'Memory', 'Date', 'Commit'], overview))
+
+    blocks.append(('heading', 2, 'Results by Stack'))
+    for stack in stacks:
+        columns = [column for column in REPORT_METRICS
+                   if any(value(run_metrics, stack, column) is not None for _, run_metrics in metrics)]
+        rows = [[label] + [format(v, column[3]) if (v := value(run_metrics, stack, column)) is not None else 'N/A'
+                           for column in columns]
+                for label, run_metrics in metrics]
+        blocks.append(('heading', 3, stack))
+        blocks.append(('table', ['Run'] + [column[0] for column in columns], rows))
+
+    charts = []
+    for name, title, heading in REPORT_CHARTS:
+        column = next(column for column in REPORT_METRICS if column[0] == heading)
+        chart_stacks = [stack for stack in stacks
+                        if any(value(run_metrics, stack, column) is not None for _, run_metrics in metrics)]
+        if chart_stacks:
+            series = [(label, [value(run_metrics, stack, column) for stack in chart_stacks])
+                      for label, run_metrics in metrics]
+            charts.append(('chart', name, title, svg_bar_chart(title, chart_stacks, series)))
+    if charts:
+        blocks.append(('heading', 2, 'Charts'))
+        blocks.extend(charts)
+    return blocks
+
+
+def render_markdown(blocks, output_path):
+    """Write the report as Markdown with each chart saved as an SVG file next to it."""
+    lines = []
+    for block in blocks:
+        if block[0] == 'heading':
+            lines += ['#' * block[1] + ' ' + block[2], '']
+        elif block[0] == 'table':
+            _, headers, rows = block
+            lines.append('| ' + ' | '.join(headers) + ' |')
+            lines.append('|' + '|'.join('-' * (len(header) + 2) for header in headers) + '|')
+            lines += ['| ' + ' | '.join(row) + ' |' for row in rows]
+            lines.append('')
+        elif block[0] == 'chart':
+            _, name, title, svg = block
+            svg_path = output_path.with_name(f"{output_path.stem}_{name}.svg")
+            svg_path.write_text(svg + '\n')
+            lines += [f"![{title}]({svg_path.name})", '']
+    output_path.write_text('\n'.join(lines))
+
+
+def render_html(blocks, output_path):
+    """Write the report as a single HTML page with the charts inline."""
+    body = []
+    for block in blocks:
+        if block[0] == 'heading':
+            body.append(f"<h{block[1]}>{html.escape(block[2])}</h{block[1]}>")
+        elif block[0] == 'table':
+            _, headers, rows = block
+            body.append('<table>')
+            body.append('<tr>' + ''.join(f"<th>{html.escape(header)}</th>" for header in headers) + '</tr>')
+            body += ['<tr>' + ''.join(f"<td>{html.escape(cell)}</td>" for cell in row) + '</tr>' for row in rows]
+            body.append('</table>')
+        elif block[0] == 'chart':
+            body.append(f"<figure>{block[3]}</figure>")
+    output_path.write_text(f"""<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>ZK-SNARK ECDSA Benchmark Report</title>
+<style>
+  body {{ font-family: sans-serif; margin: 2em; color: #222; }}
+  table {{ border-collapse: collapse; margin-bottom: 1em; }}
+  th, td {{ border: 1px solid #ccc; padding: 4px 10px; text-align: right; }}
+  th:first-child, td:first-child {{ text-align: left; }}
+  figure {{ margin: 1em 0; }}
+</style>
+</head>
+<body>
+{chr(10).join(body)}
+</body>
+</html>
+""")
+
+
+def cmd_report(args):
+    runs = report_runs(args.results)
+    if not runs:
+        sys.exit("Error: no results to report on")
+
+    output_path = Path(args.output or f"benchmark_report.{'html' if args.format == 'html' else 'md'}")
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    blocks = build_report(runs)
+    if args.format == 'html':
+        render_html(blocks, output_path)
+    else:
+        render_markdown(blocks, output_path)
+    print(f"Generated {args.format} report for {len(runs)} run(s): {output_path}")
+    return 0
+
+
+def main():
+    parser = argparse.ArgumentParser(description='Compare and report on benchmark results (performance_data.json).')
+    subparsers = parser.add_subparsers(dest='command', required=True)
+
+    compare = subparsers.add_parser(
+        'compare',
+        help='compare a run against a baseline and fail on regressions',
+        description='Compare every (stack, phase, metric) of CURRENT against BASELINE. Each may be a '
+                    'performance_data.json file, an instance directory containing one, or a '
+                    'collected_results_* directory (instances are then paired by name). Exits with 1 if '
+                    'any metric grew by more than the threshold.')
+    compare.add_argument('baseline', help='baseline results')
+    compare.add_argument('current', help='results to check')
+    compare.add_argument('--threshold', type=float, default=10.0,
+                         help='percentage increase counted as a regression (default: 10)')
+    compare.set_defaults(func=cmd_compare)
+
+    report = subparsers.add_parser(
+        'report',
+        help='render per-stack tables and bar charts for one or more runs',
+        description='Render a report with per-stack tables of witness, proving, and verification time, '
+                    'browser proving time and peak memory, proof size, and gas, plus SVG bar charts '
+                    'comparing the runs. Each RESULTS may be a performance_data.json file, an instance '
+                    'directory containing one, or a collected_results_* directory (one run per instance).')
+    report.add_argument('results', nargs='+', help='results to include')
+    report.add_argument('--format', choices=['markdown', 'html'], default='markdown',
+                        help='output format (default: markdown, with charts as SVG files next to the report)')
+    report.add_argument('-o', '--output', help='report path (default: benchmark_report.md or benchmark_report.html)')
+    report.set_defaults(func=cmd_report)
+
+    args = parser.parse_args()
+    sys.exit(args.func(args))
+
+
+if __name__ == "__main__":
+    main()
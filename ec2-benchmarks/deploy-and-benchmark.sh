@@ -59,10 +59,22 @@ OPTIONS:
     -v, --vpc-id VPC        VPC ID (required)
     -r, --region REGION     AWS region (default: us-east-1)
     -t, --test-cases NUM    Number of test cases to generate (default: 10)
+    --runs NUM              Timed runs per test case for witness and proof generation (default: 1)
+    --warmup NUM            Untimed warmup runs per test case (default: suite-specific)
+    --include-invalid       Also generate invalid test cases and check every stack rejects them
+    --batch-size NUM        Signatures per test case, all verified in one proof (default: 1)
+    --verify-runs NUM       Timed verifications per proof (default: 10)
+    --thread-scaling        Also measure proving time at 1, 2, 4, 8, and all cores
+    --browser               Also measure snarkjs and Noir proving in headless Chromium
+    --gpu                   Also prove with rapidsnark on the GPU on instances that have one
     --skip-deploy           Skip infrastructure deployment (use existing instances)
     --skip-benchmarks       Skip benchmark execution (deploy only)
     --cleanup               Destroy infrastructure after benchmarks complete
     --skip-reports          Skip report generation (just collect raw data)
+    --compare BASELINE      Compare the collected results against a baseline (a collected_results_*
+                            directory or a performance_data.json) and exit non-zero on regressions
+    --regression-threshold PCT
+                            Percentage increase of any metric counted as a regression (default: 10)
     -h, --help              Show this help message
 
 EXAMPLES:
@@ -71,6 +83,9 @@ EXAMPLES:
     
     # Use custom region and more test cases
     $0 -k my-key -s subnet-123456 -v vpc-123456 -r us-west-2 -t 20
+
+    # Prove batches of 4 signatures and report the per-signature cost
+    $0 -k my-key -s subnet-123456 -v vpc-123456 --batch-size 4
     
     # Deploy only (no benchmarks)
     $0 -k my-key -s subnet-123456 -v vpc-123456 --skip-benchmarks
@@ -83,6 +98,9 @@ EXAMPLES:
     
     # Full cycle with cleanup
     $0 -k my-key -s subnet-123456 -v vpc-123456 --cleanup
+    
+    # Fail if anything got more than 5% slower (or bigger) than a previous run
+    $0 --skip-deploy --compare collected_results_20250813_195953 --regression-threshold 5
 
 EOF
 }
@@ -90,10 +108,20 @@ EOF
 # Default values
 AWS_REGION="us-east-1"
 TEST_CASES=10
+INCLUDE_INVALID=false
+BATCH_SIZE=1
+RUNS=1
+WARMUP=""
+VERIFY_RUNS=10
+THREAD_SCALING_FLAG=""
+BROWSER_FLAG=""
+GPU_FLAG=""
 SKIP_DEPLOY=false
 SKIP_BENCHMARKS=false
 SKIP_REPORTS=false
 CLEANUP=false
+COMPARE_BASELINE=""
+REGRESSION_THRESHOLD=10
 KEY_NAME=""
 SUBNET_ID=""
 VPC_ID=""
@@ -121,6 +149,38 @@ while [[ $# -gt 0 ]]; do
             TEST_CASES="$2"
             shift 2
             ;;
+        --runs)
+            RUNS="$2"
+            shift 2
+            ;;
+        --warmup)
+            WARMUP="$2"
+            shift 2
+            ;;
+        --include-invalid)
+            INCLUDE_INVALID=true
+            shift
+            ;;
+        --batch-size)
+            BATCH_SIZE="$2"
+            shift 2
+            ;;
+        --verify-runs)
+            VERIFY_RUNS="$2"
+            shift 2
+            ;;
+        --thread-scaling)
+            THREAD_SCALING_FLAG="--thread-scaling"
+            shift
+            ;;
+        --browser)
+            BROWSER_FLAG="--browser"
+            shift
+            ;;
+        --gpu)
+            GPU_FLAG="--gpu"
+            shift
+            ;;
         --skip-deploy)
             SKIP_DEPLOY=true
             shift
@@ -137,6 +197,14 @@ while [[ $# -gt 0 ]]; do
             CLEANUP=true
             shift
             ;;
+        --compare)
+            COMPARE_BASELINE="$2"
+            shift 2
+            ;;
+        --regression-threshold)
+            REGRESSION_THRESHOLD="$2"
+            shift 2
+            ;;
         -h|--help)
             usage
             exit 0
@@ -167,6 +235,15 @@ if [ ! -f "$PROJECT_ROOT/Cargo.toml" ]; then
     exit 1
 fi
 
+# Resolve the baseline now, since the script changes directory later
+if [ -n "$COMPARE_BASELINE" ]; then
+    if [ ! -e "$COMPARE_BASELINE" ]; then
+        error "Baseline not found: $COMPARE_BASELINE"
+        exit 1
+    fi
+    COMPARE_BASELINE="$(cd "$(dirname "$COMPARE_BASELINE")" && pwd)/$(basename "$COMPARE_BASELINE")"
+fi
+
 log "Starting EC2 benchmark deployment..."
 log "Project root: $PROJECT_ROOT"
 log "Test cases to generate: $TEST_CASES"
@@ -213,6 +290,8 @@ key_name   = "$KEY_NAME"
 subnet_id  = "$SUBNET_ID"
 vpc_id     = "$VPC_ID"
 test_cases = $TEST_CASES
+include_invalid = $INCLUDE_INVALID
+batch_size = $BATCH_SIZE
 EOF
 
     # Initialize and apply Terraform
@@ -334,7 +413,7 @@ if [ "$SKIP_BENCHMARKS" = false ]; then
         
         # Make it executable and run
         ssh -i ~/.ssh/$KEY_NAME.pem -o StrictHostKeyChecking=no ubuntu@$ip \
-            "chmod +x /home/ubuntu/run-all-benchmarks.sh && cd /home/ubuntu/zk-snark-ecdsa-benchmarks && /home/ubuntu/run-all-benchmarks.sh" \
+            "chmod +x /home/ubuntu/run-all-benchmarks.sh && cd /home/ubuntu/zk-snark-ecdsa-benchmarks && /home/ubuntu/run-all-benchmarks.sh --runs $RUNS ${WARMUP:+--warmup $WARMUP} --verify-runs $VERIFY_RUNS $THREAD_SCALING_FLAG $BROWSER_FLAG $GPU_FLAG" \
             > "$SCRIPT_DIR/benchmark_${instance_type}.log" 2>&1 &
         
         log "Benchmark started on $instance_type (log: benchmark_${instance_type}.log)"
@@ -368,98 +447,13 @@ if [ "$SKIP_BENCHMARKS" = false ]; then
         # Create instance-specific directory
         mkdir -p "$RESULTS_COLLECTION_DIR/$instance_type"
         
-        # Create a comprehensive summary generation script on the instance
-        ssh -i ~/.ssh/$KEY_NAME.pem -o StrictHostKeyChecking=no ubuntu@$ip << 'REMOTE_SCRIPT'
-            LATEST_RESULTS=$(find /mnt/benchmark-data -name 'results_*' -type d | sort -r | head -n1)
-            if [ -z "$LATEST_RESULTS" ]; then echo "No results found"; exit 1; fi
-            
-            SUMMARY_DIR="/tmp/benchmark_final_summary"
-            rm -rf "$SUMMARY_DIR" && mkdir -p "$SUMMARY_DIR"
-            
-            INSTANCE_TYPE=$(curl -s http://169.254.169.254/latest/meta-data/instance-type 2>/dev/null || echo "unknown")
-            CPU_CORES=$(nproc)
-            MEMORY_GB=$(free -g | awk '/^Mem:/{print $2}')
-            
-            # --- Start JSON generation ---
-            echo "{" > "$SUMMARY_DIR/performance_data.json"
-            echo "  \"instance_type\": \"$INSTANCE_TYPE\"," >> "$SUMMARY_DIR/performance_data.json"
-            echo "  \"cpu_cores\": $CPU_CORES," >> "$SUMMARY_DIR/performance_data.json"
-            echo "  \"memory_gb\": $MEMORY_GB," >> "$SUMMARY_DIR/performance_data.json"
-            echo "  \"timestamp\": \"$(date -u +%Y-%m-%dT%H:%M:%SZ)\"," >> "$SUMMARY_DIR/performance_data.json"
-            
-            # --- Proving Times ---
-            echo "  \"proving_times\": {" >> "$SUMMARY_DIR/performance_data.json"
-            temp_entries_proving=$(mktemp)
-            for suite in snarkjs rapidsnark noir gnark; do
-                if [ -f "$LATEST_RESULTS/$suite/benchmarks/all_proofs_benchmark.json" ]; then
-                    avg_time=$(jq -r '[.results[].mean] | add / length' "$LATEST_RESULTS/$suite/benchmarks/all_proofs_benchmark.json" 2>/dev/null)
-                    if [ -n "$avg_time" ] && [ "$avg_time" != "null" ]; then
-                        echo "    \"$suite\": $avg_time" >> "$temp_entries_proving"
-                    fi
-                fi
-            done
-            if [ -s "$temp_entries_proving" ]; then
-                sed '$!s/$/,/' "$temp_entries_proving" >> "$SUMMARY_DIR/performance_data.json"
-            fi
-            rm "$temp_entries_proving"
-            echo "  }," >> "$SUMMARY_DIR/performance_data.json"
-
-            # --- Gas Costs ---
-            echo "  \"gas_costs\": {" >> "$SUMMARY_DIR/performance_data.json"
-            temp_entries_gas=$(mktemp)
-            for suite in snarkjs rapidsnark noir gnark; do
-                 gas_file_snarkjs_rapidsnark="$LATEST_RESULTS/$suite/gas-reports/reports/all_gas_data.json"
-                 gas_file_noir="$LATEST_RESULTS/$suite/gas/gas_benchmark_summary.json"
-                 avg_gas=""
-                 if [ -f "$gas_file_snarkjs_rapidsnark" ]; then
-                    avg_gas=$(jq -r '[.results[].mean] | add / length' "$gas_file_snarkjs_rapidsnark" 2>/dev/null)
-                 elif [ -f "$gas_file_noir" ]; then
-                    avg_gas=$(jq -r '[.results[].gas_used] | add / length' "$gas_file_noir" 2>/dev/null)
-                 fi
-                 if [ -n "$avg_gas" ] && [ "$avg_gas" != "null" ]; then
-                    echo "    \"$suite\": $avg_gas" >> "$temp_entries_gas"
-                 fi
-            done
-            if [ -s "$temp_entries_gas" ]; then
-                sed '$!s/$/,/' "$temp_entries_gas" >> "$SUMMARY_DIR/performance_data.json"
-            fi
-            rm "$temp_entries_gas"
-            echo "  }," >> "$SUMMARY_DIR/performance_data.json"
-
-            # --- Raw Data ---
-            echo "  \"raw_data\": {" >> "$SUMMARY_DIR/performance_data.json"
-            temp_raw_entries=$(mktemp)
-            for suite in snarkjs rapidsnark noir gnark; do
-                if [ -d "$LATEST_RESULTS/$suite" ]; then
-                    echo "    \"$suite\": {" >> "$temp_raw_entries"
-                    
-                    # Raw proving times
-                    proving_times_raw=$(jq -r '[.results[].mean] | map(select(. != null)) | join(",")' "$LATEST_RESULTS/$suite/benchmarks/all_proofs_benchmark.json" 2>/dev/null || echo "")
-                    echo "      \"proving_times\": [$proving_times_raw]," >> "$temp_raw_entries"
-
-                    # Raw gas costs
-                    gas_file_snarkjs_rapidsnark="$LATEST_RESULTS/$suite/gas-reports/reports/all_gas_data.json"
-                    gas_file_noir="$LATEST_RESULTS/$suite/gas/gas_benchmark_summary.json"
-                    gas_costs_raw=""
-                    if [ -f "$gas_file_snarkjs_rapidsnark" ]; then
-                        gas_costs_raw=$(jq -r '[.results[].mean] | map(select(. != null)) | join(",")' "$gas_file_snarkjs_rapidsnark" 2>/dev/null || echo "")
-                    elif [ -f "$gas_file_noir" ]; then
-                        gas_costs_raw=$(jq -r '[.results[].gas_used] | map(select(. != null)) | join(",")' "$gas_file_noir" 2>/dev/null || echo "")
-                    fi
-                    echo "      \"gas_costs\": [$gas_costs_raw]" >> "$temp_raw_entries"
-
-                    echo "    }" >> "$temp_raw_entries"
-                fi
-            done
-            if [ -s "$temp_raw_entries" ]; then
-                sed '$!s/^\(    }\)$/\1,/' "$temp_raw_entries" >> "$SUMMARY_DIR/performance_data.json"
-            fi
-            rm "$temp_raw_entries"
-            echo "  }" >> "$SUMMARY_DIR/performance_data.json"
-            
-            echo "}" >> "$SUMMARY_DIR/performance_data.json"
-            # --- End JSON generation ---
-REMOTE_SCRIPT
+        # Copy the summary generation script and build performance_data.json on the instance
+        scp -i ~/.ssh/$KEY_NAME.pem -o StrictHostKeyChecking=no \
+            "$SCRIPT_DIR/scripts/summarize-results.sh" \
+            ubuntu@$ip:/tmp/
+        
+        ssh -i ~/.ssh/$KEY_NAME.pem -o StrictHostKeyChecking=no ubuntu@$ip \
+            "chmod +x /tmp/summarize-results.sh && /tmp/summarize-results.sh"
         
         # Generate reports on the VM if not skipping reports
         if [ "$SKIP_REPORTS" = false ]; then
@@ -499,6 +493,21 @@ REMOTE_SCRIPT
     
     log "All results collected and processed!"
     
+    # Compare against the baseline run, if one was given
+    REGRESSIONS_FOUND=false
+    if [ -n "$COMPARE_BASELINE" ]; then
+        log "=== Comparing Against Baseline ==="
+        log "Baseline: $COMPARE_BASELINE (threshold: ${REGRESSION_THRESHOLD}%)"
+        python3 "$SCRIPT_DIR/bench_results.py" compare "$COMPARE_BASELINE" "$RESULTS_COLLECTION_DIR" \
+            --threshold "$REGRESSION_THRESHOLD" | tee "$RESULTS_COLLECTION_DIR/baseline_comparison.txt"
+        if [ "${PIPESTATUS[0]}" -eq 0 ]; then
+            log "No regressions against the baseline"
+        else
+            error "Regressions found against the baseline (see baseline_comparison.txt)"
+            REGRESSIONS_FOUND=true
+        fi
+    fi
+    
 fi
 
 # Cleanup infrastructure if requested
@@ -536,6 +545,9 @@ if [ "$SKIP_BENCHMARKS" = false ]; then
         log ""
         log "To generate reports later, you can run on any VM or locally:"
         log "  python3 $SCRIPT_DIR/generate_reports.py <path_to_performance_data.json>"
+        log ""
+        log "To compare instances in one Markdown or HTML report:"
+        log "  python3 $SCRIPT_DIR/bench_results.py report $RESULTS_COLLECTION_DIR --format html"
     fi
 fi
 
@@ -543,4 +555,8 @@ if [ "$CLEANUP" = false ] && [ "$SKIP_DEPLOY" = false ]; then
     echo
     warn "EC2 instances are still running. Don't forget to destroy them when done:"
     warn "cd $SCRIPT_DIR/terraform && terraform destroy"
+fi
+
+if [ "$REGRESSIONS_FOUND" = true ]; then
+    exit 1
 fi 
\ No newline at end of file
@@ -11,8 +11,20 @@ def generate_summary_markdown(data, output_dir):
     cpu_cores = data.get('cpu_cores', 'N/A')
     memory_gb = data.get('memory_gb', 'N/A')
     timestamp = data.get('timestamp', 'N/A')
+    witness_times = data.get('witness_times', {})
+    witness_generation = data.get('witness_generation', {})
     proving_times = data.get('proving_times', {})
+    verification_times = data.get('verification_times', {})
+    verification_medians = data.get('verification_medians', {})
     gas_costs = data.get('gas_costs', {})
+    onchain_verification = data.get('onchain_verification', {})
+    artifact_sizes = data.get('artifact_sizes', {})
+    timing_stats = data.get('timing_stats', {})
+    thread_scaling = data.get('thread_scaling', {})
+    soundness = data.get('soundness', {})
+    metadata = data.get('metadata', {})
+    browser_proving = data.get('browser_proving', {})
+    amortized = data.get('amortized', {})
     
     md_content = f"""# ZK-SNARK ECDSA Benchmark Results
 
@@ -24,14 +36,109 @@ def generate_summary_markdown(data, output_dir):
 ## Performance Summary
 """
     
-    suites = sorted(list(set(list(proving_times.keys()) + list(gas_costs.keys()))))
+    suites = sorted(list(set(list(proving_times.keys()) + list(verification_times.keys()) + list(gas_costs.keys()) + list(artifact_sizes.keys()))))
     
     for suite in suites:
         md_content += f"\n### {suite}\n\n"
+        if suite in witness_times:
+            md_content += f"- **Witness Generation Time:** {witness_times[suite]:.3f}s"
+            if suite in witness_generation:
+                generation = witness_generation[suite]
+                md_content += f" ({generation['generator']}{', cached' if generation.get('cached') else ''})"
+            md_content += "\n"
         if suite in proving_times:
             md_content += f"- **Proving Time:** {proving_times[suite]:.3f}s\n"
+        if suite in witness_times and suite in proving_times:
+            md_content += f"- **Witness + Proving Time:** {witness_times[suite] + proving_times[suite]:.3f}s\n"
+        if suite in verification_times:
+            md_content += f"- **Verification Time:** {verification_times[suite] * 1000:.2f}ms (mean)"
+            if suite in verification_medians:
+                md_content += f", {verification_medians[suite] * 1000:.2f}ms (median)"
+            md_content += "\n"
         if suite in gas_costs:
             md_content += f"- **Gas Cost:** {int(gas_costs[suite]):,} gas\n"
+        if suite in onchain_verification:
+            onchain = onchain_verification[suite]
+            md_content += (f"- **On-chain Verification:** {int(onchain['gas_used']):,} gas per transaction, "
+                           f"{int(onchain['calldata_bytes']):,} bytes of calldata "
+                           f"({onchain['verifier']}: {int(onchain['bytecode_bytes']):,} bytes, "
+                           f"{int(onchain['deployment_gas']):,} gas to deploy")
+            if onchain.get('exceeds_code_size_limit'):
+                md_content += ", over the 24,576 byte contract size limit"
+            md_content += ")\n"
+        if amortized.get(suite, {}).get('batch_size', 1) > 1:
+            batch = amortized[suite]
+            md_content += f"- **Batch Size:** {batch['batch_size']} signatures per proof\n"
+            md_content += f"- **Per Signature:** {batch['proving_time']:.3f}s proving"
+            if batch.get('verification_time') is not None:
+                md_content += f", {batch['verification_time'] * 1000:.2f}ms verification"
+            if batch.get('gas') is not None:
+                md_content += f", {int(batch['gas']):,} gas"
+            if batch.get('onchain_gas') is not None:
+                md_content += f", {int(batch['onchain_gas']):,} gas per transaction"
+            md_content += "\n"
+        if suite in artifact_sizes:
+            sizes = artifact_sizes[suite]
+            for key, label in [('proof_bytes', 'Proof Size'),
+                               ('public_inputs_bytes', 'Public Inputs Size'),
+                               ('proving_key_bytes', 'Proving Key Size'),
+                               ('verification_key_bytes', 'Verification Key Size'),
+                               ('srs_bytes', 'SRS Size')]:
+                if sizes.get(key) is not None:
+                    md_content += f"- **{label}:** {int(sizes[key]):,} bytes\n"
+        if suite in soundness:
+            result = soundness[suite]
+            status = "✅" if result['rejected'] == result['invalid_cases'] else "❌"
+            md_content += f"- **Soundness:** {status} {result['rejected']}/{result['invalid_cases']} invalid test cases rejected\n"
+
+    if timing_stats:
+        md_content += "\n## Timing Statistics\n\n"
+        md_content += "Outliers fall outside 1.5 × IQR of the quartiles and are excluded only from the last column.\n\n"
+        md_content += "| Suite | Phase | Runs | Mean (s) | Median (s) | Std Dev (s) | Min (s) | Max (s) | Outliers | Mean w/o Outliers (s) |\n"
+        md_content += "|-------|-------|------|----------|------------|-------------|---------|---------|----------|-----------------------|\n"
+        for suite in sorted(timing_stats.keys()):
+            for phase in ['witnesses', 'proofs', 'verifications']:
+                stats = timing_stats[suite].get(phase)
+                if not stats:
+                    continue
+                md_content += (f"| {suite} | {phase} | {stats['runs']} | {stats['mean']:.3f} | {stats['median']:.3f} | "
+                               f"{stats['stddev']:.3f} | {stats['min']:.3f} | {stats['max']:.3f} | {stats['outliers']} | "
+                               f"{stats['mean_without_outliers']:.3f} |\n")
+
+    if thread_scaling:
+        md_content += "\n## Thread Scaling\n\n"
+        md_content += "| Suite | Threads | Proving Time (s) | Speedup |\n"
+        md_content += "|-------|---------|------------------|---------|\n"
+        for suite in sorted(thread_scaling.keys()):
+            for point in thread_scaling[suite]:
+                md_content += f"| {suite} | {point['threads']} | {point['mean']:.3f} | {point['speedup']:.2f}x |\n"
+
+    if browser_proving:
+        md_content += "\n## Browser Proving\n\n"
+        md_content += "| Suite | Mean (s) | Median (s) | Witness (s) | Native Proving (s) | Peak Memory (MB) | Failed |\n"
+        md_content += "|-------|----------|------------|-------------|--------------------|------------------|--------|\n"
+        fmt = lambda value, spec: format(value, spec) if value is not None else "N/A"
+        for suite in sorted(browser_proving.keys()):
+            result = browser_proving[suite]
+            peak_mb = result['peak_rss_bytes'] / 1024 / 1024 if result.get('peak_rss_bytes') is not None else None
+            md_content += (f"| {suite} | {fmt(result['mean'], '.3f')} | {fmt(result['median'], '.3f')} | "
+                           f"{fmt(result['witness_mean'], '.3f')} | {fmt(proving_times.get(suite), '.3f')} | "
+                           f"{fmt(peak_mb, ',.0f')} | {result['failed']}/{result['test_cases']} |\n")
+
+    if metadata:
+        md_content += "\n## Environment\n\n"
+        host = metadata.get('host', {})
+        for key, label in [('cpu_model', 'CPU'), ('os', 'OS'), ('kernel', 'Kernel'),
+                           ('arch', 'Architecture'), ('docker_version', 'Docker'), ('git_commit', 'Commit')]:
+            if host.get(key):
+                md_content += f"- **{label}:** {host[key]}\n"
+        suite_metadata = {suite: meta for suite, meta in metadata.items() if suite != 'host'}
+        if suite_metadata:
+            md_content += "\n| Suite | Tool | Version |\n"
+            md_content += "|-------|------|---------|\n"
+            for suite in sorted(suite_metadata.keys()):
+                for tool, version in suite_metadata[suite].get('tool_versions', {}).items():
+                    md_content += f"| {suite} | {tool} | {version or 'not found'} |\n"
             
     summary_path = Path(output_dir) / 'performance_summary.md'
     with open(summary_path, 'w') as f:
@@ -140,6 +247,86 @@ def generate_plots(json_file_path):
         plt.close()
         print("Generated proving_times.png")
     
+    # Generate verification times plot with min, median, average, max
+    if verification_times:
+        verification_medians = data.get('verification_medians', {})
+        suites = list(verification_times.keys())
+        avg_times = [verification_times[suite] * 1000 for suite in suites]
+        median_times = [verification_medians.get(suite, verification_times[suite]) * 1000 for suite in suites]
+        
+        # Per-proof means from raw data give the spread across test cases
+        min_times = []
+        max_times = []
+        
+        for i, suite in enumerate(suites):
+            individual_times = raw_data.get(suite, {}).get('verification_times', [])
+            if individual_times:
+                min_times.append(min(individual_times) * 1000)
+                max_times.append(max(individual_times) * 1000)
+            else:
+                min_times.append(avg_times[i])
+                max_times.append(avg_times[i])
+        
+        # Create grouped bar chart
+        x = np.arange(len(suites))
+        width = 0.2
+        
+        plt.figure(figsize=(12, 7))
+        
+        bars1 = plt.bar(x - 1.5 * width, min_times, width, label='Minimum', alpha=0.8, color='#2ca02c')
+        bars2 = plt.bar(x - 0.5 * width, median_times, width, label='Median', alpha=0.8, color='#9467bd')
+        bars3 = plt.bar(x + 0.5 * width, avg_times, width, label='Average', alpha=0.8, color='#1f77b4')
+        bars4 = plt.bar(x + 1.5 * width, max_times, width, label='Maximum', alpha=0.8, color='#d62728')
+        
+        plt.xlabel('ZK-SNARK Suite')
+        plt.ylabel('Verification Time (milliseconds)')
+        plt.title(f'ZK-SNARK Verification Times - {instance_type}')
+        plt.xticks(x, suites, rotation=45)
+        plt.grid(True, alpha=0.3)
+        plt.legend(title='Statistics', bbox_to_anchor=(1.05, 1), loc='upper left', borderaxespad=0.)
+        
+        # Add value labels on bars
+        def add_value_labels(bars, values):
+            for bar, value in zip(bars, values):
+                plt.text(bar.get_x() + bar.get_width()/2, bar.get_height() + max(max_times)*0.01,
+                        f'{value:.1f}', ha='center', va='bottom', fontsize=8, rotation=0)
+        
+        add_value_labels(bars1, min_times)
+        add_value_labels(bars2, median_times)
+        add_value_labels(bars3, avg_times)
+        add_value_labels(bars4, max_times)
+        
+        plt.tight_layout()
+        plt.savefig(output_dir / 'verification_times.png', dpi=300, bbox_inches='tight')
+        plt.close()
+        print("Generated verification_times.png")
+    
+    # Generate thread scaling plot with speedup curves
+    thread_scaling = data.get('thread_scaling', {})
+    if thread_scaling:
+        plt.figure(figsize=(10, 7))
+        
+        max_threads = 1
+        for suite, points in thread_scaling.items():
+            threads = [point['threads'] for point in points]
+            speedups = [point['speedup'] for point in points]
+            plt.plot(threads, speedups, marker='o', label=suite)
+            max_threads = max(max_threads, max(threads))
+        
+        # Ideal linear scaling for reference
+        plt.plot([1, max_threads], [1, max_threads], linestyle='--', color='gray', label='Linear')
+        
+        plt.xlabel('Threads')
+        plt.ylabel('Speedup over 1 thread')
+        plt.title(f'ZK-SNARK Proving Thread Scaling - {instance_type}')
+        plt.grid(True, alpha=0.3)
+        plt.legend()
+        
+        plt.tight_layout()
+        plt.savefig(output_dir / 'thread_scaling.png', dpi=300, bbox_inches='tight')
+        plt.close()
+        print("Generated thread_scaling.png")
+    
     # Generate gas consumption plot with min, max, average
     if gas_costs:
         suites = list(gas_costs.keys())
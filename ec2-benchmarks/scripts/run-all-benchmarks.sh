@@ -20,16 +20,112 @@ error() {
     echo -e "${RED}[$(date +'%Y-%m-%d %H:%M:%S')] ERROR: $1${NC}"
 }
 
-# Check if we're in the right directory
-if [ ! -f "pot22_final.ptau" ]; then
+# Same hash as bench.py's circuit_fingerprint for the suite in $1: its circom
+# sources, then the batch size and chunk layout from its test manifest
+circuit_fingerprint() {
+    {
+        for source in circuit.circom batch.circom; do
+            if [ -f "$1/$source" ]; then cat "$1/$source"; fi
+        done
+        jq -jcS '{batch_size: (.batch_size // 1), chunk_layout}' "$1/tests/manifest.json" 2>/dev/null \
+            || printf '{"batch_size":1,"chunk_layout":null}'
+    } | sha256sum | cut -d' ' -f1
+}
+
+usage() {
+    cat << EOF
+Usage: $0 [OPTIONS]
+
+Run all ZK-SNARK ECDSA benchmark suites on this machine
+
+OPTIONS:
+    --runs NUM              Timed runs per test case for witness and proof generation (default: 1)
+    --warmup NUM            Untimed warmup runs per test case (default: suite-specific)
+    --verify-runs NUM       Timed verifications per proof (default: 10)
+    --thread-scaling        Also measure proving time at 1, 2, 4, 8, and all cores
+    --browser               Also measure snarkjs and Noir proving in headless Chromium
+    --gpu                   Also prove rapidsnark's circuit on a CUDA GPU with ICICLE (stack rapidsnark-gpu)
+    -h, --help              Show this help message
+EOF
+}
+
+# Default values
+RUNS=1
+WARMUP=""
+VERIFY_RUNS=10
+THREAD_SCALING=false
+BROWSER=false
+GPU=false
+
+# Parse command line arguments
+while [[ $# -gt 0 ]]; do
+    case $1 in
+        --runs)
+            RUNS="$2"
+            shift 2
+            ;;
+        --warmup)
+            WARMUP="$2"
+            shift 2
+            ;;
+        --verify-runs)
+            VERIFY_RUNS="$2"
+            shift 2
+            ;;
+        --thread-scaling)
+            THREAD_SCALING=true
+            shift
+            ;;
+        --browser)
+            BROWSER=true
+            shift
+            ;;
+        --gpu)
+            GPU=true
+            shift
+            ;;
+        -h|--help)
+            usage
+            exit 0
+            ;;
+        *)
+            error "Unknown option: $1"
+            usage
+            exit 1
+            ;;
+    esac
+done
+
+if ! [[ "$RUNS" =~ ^[1-9][0-9]*$ ]]; then
+    error "--runs must be a positive integer"
+    exit 1
+fi
+
+if [ -n "$WARMUP" ] && ! [[ "$WARMUP" =~ ^[0-9]+$ ]]; then
+    error "--warmup must be a non-negative integer"
+    exit 1
+fi
+
+if ! [[ "$VERIFY_RUNS" =~ ^[1-9][0-9]*$ ]]; then
+    error "--verify-runs must be a positive integer"
+    exit 1
+fi
+
+# Check if we're in the right directory (with a Powers of Tau, downloaded by hand or by `bench.py setup`)
+if [ ! -f "pot22_final.ptau" ] && [ ! -f "artifacts/setup.json" ]; then
     error "Not in the correct directory. Please run from the zk-snark-ecdsa-benchmarks root directory."
+    error "If you are, run 'python3 bench.py setup' (or download pot22_final.ptau) first."
     exit 1
 fi
+ROOT_DIR=$(pwd)
 
 # Get system information
 CPU_CORES=$(nproc)
 MEMORY_GB=$(free -g | awk '/^Mem:/{print $2}')
 INSTANCE_TYPE=$(curl -s http://169.254.169.254/latest/meta-data/instance-type 2>/dev/null || echo "local")
+CPU_MODEL=$(lscpu 2>/dev/null | awk -F: '/^Model name/ { sub(/^[ \t]+/, "", $2); print $2; exit }')
+OS_NAME=$(. /etc/os-release 2>/dev/null && echo "$PRETTY_NAME")
+GIT_COMMIT=$(git rev-parse HEAD 2>/dev/null || true)
 
 log "Starting benchmarks on $INSTANCE_TYPE with $CPU_CORES CPU cores and ${MEMORY_GB}GB RAM"
 
@@ -38,16 +134,32 @@ TIMESTAMP=$(date +%Y%m%d_%H%M%S)
 RESULTS_DIR="/mnt/benchmark-data/results_${INSTANCE_TYPE}_${TIMESTAMP}"
 mkdir -p "$RESULTS_DIR"
 
-# Save system info
-cat > "$RESULTS_DIR/system_info.json" << EOF
-{
-  "instance_type": "$INSTANCE_TYPE",
-  "cpu_cores": $CPU_CORES,
-  "memory_gb": $MEMORY_GB,
-  "timestamp": "$TIMESTAMP",
-  "started_at": "$(date -u +%Y-%m-%dT%H:%M:%SZ)"
-}
-EOF
+# Save system info (built with jq since the CPU model and OS name are free-form strings)
+jq -n \
+    --arg instance_type "$INSTANCE_TYPE" \
+    --arg cpu_model "$CPU_MODEL" \
+    --argjson cpu_cores "$CPU_CORES" \
+    --argjson memory_gb "$MEMORY_GB" \
+    --arg os "$OS_NAME" \
+    --arg kernel "$(uname -r)" \
+    --arg arch "$(uname -m)" \
+    --arg docker_version "$(docker --version 2>/dev/null || true)" \
+    --arg git_commit "$GIT_COMMIT" \
+    --arg timestamp "$TIMESTAMP" \
+    --arg started_at "$(date -u +%Y-%m-%dT%H:%M:%SZ)" \
+    '{
+        instance_type: $instance_type,
+        cpu_model: $cpu_model,
+        cpu_cores: $cpu_cores,
+        memory_gb: $memory_gb,
+        os: $os,
+        kernel: $kernel,
+        arch: $arch,
+        docker_version: $docker_version,
+        git_commit: $git_commit,
+        timestamp: $timestamp,
+        started_at: $started_at
+    } | map_values(if . == "" then null else . end)' > "$RESULTS_DIR/system_info.json"
 
 # Coordinated Memory Allocation Strategy:
 # - Docker containers get enough memory to run Node.js processes plus overhead
@@ -76,6 +188,9 @@ else
     DOCKER_MEMORY_LIMIT="2560m"  # 2.5GB in MB
 fi
 
+# CUDA base image for GPU stacks (--gpu)
+GPU_BASE_IMAGE="nvidia/cuda:12.2.2-devel-ubuntu22.04"
+
 # Common Docker flags for performance optimization
 DOCKER_FLAGS="--cpus=${CPU_CORES} --memory=${DOCKER_MEMORY_LIMIT} --memory-swap=${DOCKER_MEMORY_LIMIT} --shm-size=1g"
 
@@ -83,10 +198,25 @@ DOCKER_FLAGS="--cpus=${CPU_CORES} --memory=${DOCKER_MEMORY_LIMIT} --memory-swap=
 MEMORY_MB=$((MEMORY_GB * 1024))
 
 # Environment variables to pass to containers (including the calculated Node.js memory)
-DOCKER_ENV="-e HOST_MEMORY_MB=$MEMORY_MB -e HOST_MEMORY_GB=$MEMORY_GB -e NODE_MEMORY_MB=$NODE_MEMORY_MB"
+DOCKER_ENV="-e HOST_MEMORY_MB=$MEMORY_MB -e HOST_MEMORY_GB=$MEMORY_GB -e NODE_MEMORY_MB=$NODE_MEMORY_MB -e BENCH_RUNS=$RUNS -e VERIFY_RUNS=$VERIFY_RUNS"
+if [ -n "$WARMUP" ]; then
+    DOCKER_ENV="$DOCKER_ENV -e BENCH_WARMUP=$WARMUP"
+fi
+if [ "$THREAD_SCALING" = true ]; then
+    DOCKER_ENV="$DOCKER_ENV -e THREAD_SCALING=1"
+fi
+if [ -n "$GIT_COMMIT" ]; then
+    DOCKER_ENV="$DOCKER_ENV -e GIT_COMMIT=$GIT_COMMIT"
+fi
 
 log "Using Docker flags: $DOCKER_FLAGS"
 log "Memory allocation: Host=${MEMORY_GB}GB, Docker=${DOCKER_MEMORY_LIMIT}, Node.js=${NODE_MEMORY_MB}MB"
+log "Runs per test case: $RUNS (warmup: ${WARMUP:-suite default})"
+log "Verification runs per proof: $VERIFY_RUNS"
+log "Thread scaling: $THREAD_SCALING"
+log "Browser proving: $BROWSER"
+log "GPU proving: $GPU"
+log "Git commit: ${GIT_COMMIT:-unknown}"
 
 # Function to run a benchmark suite
 run_benchmark() {
@@ -96,30 +226,61 @@ run_benchmark() {
     log "=== Starting $description ==="
     local start_time=$(date +%s)
     
-    cd "$suite"
+    # GPU stacks are built from their CPU stack's directory
+    local suite_dir=${suite%-gpu}
+    cd "$suite_dir"
     
     # Build the Docker image
     log "Building Docker image for $suite"
-    docker build -t "zk-ecdsa-$suite" .
+    if [ "$suite" != "$suite_dir" ]; then
+        docker build --build-arg BASE_IMAGE="$GPU_BASE_IMAGE" --build-arg GPU=1 -t "zk-ecdsa-$suite" .
+    else
+        docker build -t "zk-ecdsa-$suite" .
+    fi
     
     # Create suite-specific results directory
     local suite_results="$RESULTS_DIR/$suite"
     mkdir -p "$suite_results"
     
+    # Groth16 stacks get a Powers of Tau, plus the setup cached by `bench.py setup` when it
+    # was built for the current circuit and test case layout
+    local groth16_mounts=""
+    if [ "$suite_dir" = "snarkjs" ] || [ "$suite_dir" = "rapidsnark" ]; then
+        local ptau="$ROOT_DIR/pot22_final.ptau"
+        local cached_ptau=$(jq -r --arg suite "$suite_dir" '.stacks[$suite].ptau // empty' "$ROOT_DIR/artifacts/setup.json" 2>/dev/null || true)
+        local cached_fingerprint=$(jq -r --arg suite "$suite_dir" '.stacks[$suite].circuit_fingerprint // empty' "$ROOT_DIR/artifacts/setup.json" 2>/dev/null || true)
+        if [ -n "$cached_ptau" ] && [ -f "$ROOT_DIR/artifacts/$suite_dir/setup/circuit.zkey" ]; then
+            if [ "$cached_fingerprint" = "$(circuit_fingerprint "$ROOT_DIR/$suite_dir")" ]; then
+                log "Reusing the cached trusted setup in artifacts/$suite_dir"
+                ptau="$ROOT_DIR/artifacts/$cached_ptau"
+                groth16_mounts="-v $ROOT_DIR/artifacts/$suite_dir:/artifacts:ro -e CIRCUIT_FINGERPRINT=$cached_fingerprint"
+            else
+                warn "The setup cached in artifacts/$suite_dir was built for a different circuit or test case layout; compiling and running the setup again (run python3 bench.py setup to refresh the cache)"
+            fi
+        fi
+        groth16_mounts="$groth16_mounts -v $ptau:/app/$(basename "$ptau"):ro -e PTAU=$(basename "$ptau")"
+    fi
+
     # Run the benchmark with performance optimizations
     log "Running $suite benchmark with optimized settings"
     
+    local exit_code=0
     case $suite in
         "snarkjs")
-            docker run $DOCKER_FLAGS $DOCKER_ENV \
-                -v "$(pwd)/../pot22_final.ptau:/app/pot22_final.ptau:ro" \
+            docker run $DOCKER_FLAGS $DOCKER_ENV $groth16_mounts \
                 -v "$suite_results:/out" \
                 --name "zk-ecdsa-$suite-benchmark-$TIMESTAMP" \
                 "zk-ecdsa-$suite"
             ;;
         "rapidsnark")
-            docker run $DOCKER_FLAGS $DOCKER_ENV \
-                -v "$(pwd)/../pot22_final.ptau:/app/pot22_final.ptau:ro" \
+            docker run $DOCKER_FLAGS $DOCKER_ENV $groth16_mounts \
+                -v "$(pwd)/tests:/app/tests:ro" \
+                -v "$suite_results:/out" \
+                --name "zk-ecdsa-$suite-benchmark-$TIMESTAMP" \
+                "zk-ecdsa-$suite"
+            ;;
+        "rapidsnark-gpu")
+            docker run $DOCKER_FLAGS $DOCKER_ENV $groth16_mounts --gpus all \
                 -v "$(pwd)/tests:/app/tests:ro" \
                 -v "$suite_results:/out" \
                 --name "zk-ecdsa-$suite-benchmark-$TIMESTAMP" \
@@ -139,7 +300,31 @@ run_benchmark() {
                 --name "zk-ecdsa-$suite-benchmark-$TIMESTAMP" \
                 "zk-ecdsa-$suite"
             ;;
-    esac
+        "browser")
+            # Proves with the circuits built by the native snarkjs and Noir runs
+            docker run $DOCKER_FLAGS $DOCKER_ENV \
+                -e "BROWSER_STACKS=$BROWSER_STACKS" \
+                -v "$(pwd)/../snarkjs/tests:/app/tests/snarkjs:ro" \
+                -v "$(pwd)/../noir/tests:/app/tests/noir:ro" \
+                -v "$RESULTS_DIR/snarkjs:/in/snarkjs:ro" \
+                -v "$RESULTS_DIR/noir:/in/noir:ro" \
+                -v "$suite_results:/out" \
+                --name "zk-ecdsa-$suite-benchmark-$TIMESTAMP" \
+                "zk-ecdsa-$suite"
+            ;;
+    esac || exit_code=$?
+
+    # A suite whose circuit accepts invalid test cases exits with 1 after
+    # finishing its other steps; that is reported with the soundness summary
+    # below rather than stopping the remaining suites
+    if [ $exit_code -ne 0 ]; then
+        if [ "$(jq -r '.all_rejected' "$suite_results/benchmarks/soundness.json" 2>/dev/null)" = "false" ]; then
+            warn "$suite accepted invalid test cases"
+        else
+            error "$suite benchmark failed"
+            return $exit_code
+        fi
+    fi
     
     # Calculate execution time
     local end_time=$(date +%s)
@@ -218,6 +403,24 @@ else
     SKIPPED_SUITES+=("rapidsnark")
 fi
 
+# Optionally prove rapidsnark's circuit on the GPU, where a CUDA device is available
+if [ "$GPU" = true ]; then
+    if ! nvidia-smi -L >/dev/null 2>&1; then
+        warn "Skipping RapidSnark GPU - no NVIDIA GPU found"
+        SKIPPED_SUITES+=("rapidsnark-gpu")
+    elif ! docker info 2>/dev/null | grep -qi nvidia; then
+        warn "Skipping RapidSnark GPU - Docker has no NVIDIA runtime (install the NVIDIA container toolkit)"
+        SKIPPED_SUITES+=("rapidsnark-gpu")
+    elif [ "$MEMORY_MB" -lt "$RAPIDSNARK_MIN_MEMORY" ]; then
+        warn "Skipping RapidSnark GPU - insufficient memory (${MEMORY_MB}MB < ${RAPIDSNARK_MIN_MEMORY}MB)"
+        SKIPPED_SUITES+=("rapidsnark-gpu")
+    else
+        log "Found $(nvidia-smi -L | head -n1)"
+        run_benchmark "rapidsnark-gpu" "RapidSnark GPU (ICICLE) ECDSA Benchmarks"
+        COMPLETED_SUITES+=("rapidsnark-gpu")
+    fi
+fi
+
 # Check and run Noir
 if [ "$MEMORY_MB" -ge "$NOIR_MIN_MEMORY" ]; then
     log "Memory sufficient for Noir (${MEMORY_MB}MB >= ${NOIR_MIN_MEMORY}MB)"
@@ -238,9 +441,38 @@ else
     SKIPPED_SUITES+=("gnark")
 fi
 
+# Optionally prove in the browser, reusing the snarkjs and Noir circuit artifacts
+if [ "$BROWSER" = true ]; then
+    BROWSER_STACKS=""
+    for suite in snarkjs noir; do
+        if [[ " ${COMPLETED_SUITES[*]} " =~ " $suite " ]]; then
+            BROWSER_STACKS="$BROWSER_STACKS $suite"
+        fi
+    done
+    BROWSER_STACKS="${BROWSER_STACKS# }"
+    if [ -n "$BROWSER_STACKS" ]; then
+        log "Proving in the browser for: $BROWSER_STACKS"
+        run_benchmark "browser" "Browser (WASM) ECDSA Benchmarks"
+        COMPLETED_SUITES+=("browser")
+    else
+        warn "Skipping browser benchmarks - neither snarkjs nor Noir completed"
+        SKIPPED_SUITES+=("browser")
+    fi
+fi
+
 benchmark_end=$(date +%s)
 total_duration=$((benchmark_end - benchmark_start))
 
+# Every suite must reject the invalid test cases (only present when generated with --include-invalid)
+SOUNDNESS_FAILURES=()
+for suite in "${COMPLETED_SUITES[@]}"; do
+    soundness_file="$RESULTS_DIR/$suite/benchmarks/soundness.json"
+    if [ -f "$soundness_file" ] && [ "$(jq -r '.all_rejected' "$soundness_file")" != "true" ]; then
+        error "$suite accepted invalid test cases: $(jq -r '[.results[] | select(.rejected | not) | .test_case] | join(", ")' "$soundness_file")"
+        SOUNDNESS_FAILURES+=("$suite")
+    fi
+done
+
 # Create summary report
 cat > "$RESULTS_DIR/summary.json" << EOF
 {
@@ -250,16 +482,24 @@ cat > "$RESULTS_DIR/summary.json" << EOF
   "memory_mb": $MEMORY_MB,
   "total_duration_seconds": $total_duration,
   "docker_memory_limit": "$DOCKER_MEMORY_LIMIT",
+  "runs": $RUNS,
+  "warmup_runs": ${WARMUP:-null},
+  "verify_runs": $VERIFY_RUNS,
+  "thread_scaling": $THREAD_SCALING,
+  "browser": $BROWSER,
+  "gpu": $GPU,
   "started_at": "$(date -d @$benchmark_start -u +%Y-%m-%dT%H:%M:%SZ)",
   "completed_at": "$(date -u +%Y-%m-%dT%H:%M:%SZ)",
   "suites_completed": [$(printf '"%s",' "${COMPLETED_SUITES[@]}" | sed 's/,$//')]$([ ${#COMPLETED_SUITES[@]} -eq 0 ] && echo ''),
   "suites_skipped": [$(printf '"%s",' "${SKIPPED_SUITES[@]}" | sed 's/,$//')]$([ ${#SKIPPED_SUITES[@]} -eq 0 ] && echo ''),
+  "soundness_failures": [$([ ${#SOUNDNESS_FAILURES[@]} -gt 0 ] && printf '"%s",' "${SOUNDNESS_FAILURES[@]}" | sed 's/,$//')],
   "memory_requirements": {
     "snarkjs_min_mb": $SNARKJS_MIN_MEMORY,
     "rapidsnark_min_mb": $RAPIDSNARK_MIN_MEMORY,
     "noir_min_mb": $NOIR_MIN_MEMORY,
     "gnark_min_mb": $GNARK_MIN_MEMORY
-  }
+  },
+  "metadata": $(cat "$RESULTS_DIR/system_info.json")
 }
 EOF
 
@@ -300,13 +540,14 @@ EOF
 
 ## Skipped Suites
 
-The following suites were skipped due to insufficient memory:
+The following suites were skipped due to insufficient memory or missing hardware:
 
 EOF
         for suite in "${SKIPPED_SUITES[@]}"; do
             case $suite in
                 "snarkjs") echo "- **$suite**: Requires ${SNARKJS_MIN_MEMORY}MB memory (15GB system)" >> "$RESULTS_DIR/performance_comparison.md" ;;
                 "rapidsnark") echo "- **$suite**: Requires ${RAPIDSNARK_MIN_MEMORY}MB memory (15GB system)" >> "$RESULTS_DIR/performance_comparison.md" ;;
+                "rapidsnark-gpu") echo "- **$suite**: Requires an NVIDIA GPU, the NVIDIA container toolkit, and ${RAPIDSNARK_MIN_MEMORY}MB memory" >> "$RESULTS_DIR/performance_comparison.md" ;;
                 "noir") echo "- **$suite**: Requires ${NOIR_MIN_MEMORY}MB memory (2GB system)" >> "$RESULTS_DIR/performance_comparison.md" ;;
                 "gnark") echo "- **$suite**: Requires ${GNARK_MIN_MEMORY}MB memory (2GB system)" >> "$RESULTS_DIR/performance_comparison.md" ;;
             esac
@@ -332,4 +573,9 @@ fi
 log "Performing final Docker cleanup..."
 docker system prune -f 2>/dev/null || true
 
+if [ ${#SOUNDNESS_FAILURES[@]} -gt 0 ]; then
+    error "Soundness check failed for: ${SOUNDNESS_FAILURES[*]}"
+    exit 1
+fi
+
 log "Benchmark suite execution completed successfully!" 
\ No newline at end of file
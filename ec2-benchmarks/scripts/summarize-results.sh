@@ -0,0 +1,413 @@
+#!/bin/bash
+set -e
+
+# Builds performance_data.json from the per-suite outputs of run-all-benchmarks.sh
+#
+# Usage: summarize-results.sh [results_dir] [summary_dir]
+#   results_dir  defaults to the latest /mnt/benchmark-data/results_* directory
+#   summary_dir  defaults to /tmp/benchmark_final_summary
+
+LATEST_RESULTS=${1:-$(find /mnt/benchmark-data -name 'results_*' -type d | sort -r | head -n1)}
+if [ -z "$LATEST_RESULTS" ]; then echo "No results found"; exit 1; fi
+
+SUMMARY_DIR=${2:-/tmp/benchmark_final_summary}
+rm -rf "$SUMMARY_DIR" && mkdir -p "$SUMMARY_DIR"
+
+# jq definitions shared by the filters below. quantile expects a sorted array.
+JQ_DEFS='
+def quantile($p): ((length - 1) * $p) as $h | ($h | floor) as $lo | .[$lo] + ($h - $lo) * ((.[$lo + 1] // .[$lo]) - .[$lo]);
+def median: sort | if length == 0 then null else quantile(0.5) end;
+'
+
+# Per-phase statistics over every timed run. Runs outside the Tukey fences
+# (1.5 × IQR beyond the quartiles) are counted as outliers and excluded from
+# mean_without_outliers; all other fields use every run.
+STATS_FILTER="$JQ_DEFS"'
+[.results[].times[]] | sort | length as $n |
+if $n == 0 then null else
+    (add / $n) as $mean |
+    quantile(0.5) as $median |
+    quantile(0.25) as $q1 |
+    quantile(0.75) as $q3 |
+    ($q3 - $q1) as $iqr |
+    map(select(. >= $q1 - 1.5 * $iqr and . <= $q3 + 1.5 * $iqr)) as $inliers |
+    {
+        runs: $n,
+        mean: $mean,
+        median: $median,
+        stddev: (map(($mean - .) * ($mean - .)) | add / $n | sqrt),
+        min: .[0],
+        max: .[-1],
+        outliers: ($n - ($inliers | length)),
+        mean_without_outliers: ($inliers | add / length)
+    }
+end'
+
+INSTANCE_TYPE=$(curl -s http://169.254.169.254/latest/meta-data/instance-type 2>/dev/null || echo "unknown")
+CPU_CORES=$(nproc)
+MEMORY_GB=$(free -g | awk '/^Mem:/{print $2}')
+# Describe the machine the results were measured on, which is not this one for
+# remote device runs (bench.py device)
+if [ -f "$LATEST_RESULTS/system_info.json" ]; then
+    INSTANCE_TYPE=$(jq -r '.instance_type // "unknown"' "$LATEST_RESULTS/system_info.json")
+    CPU_CORES=$(jq -r --argjson cores "$CPU_CORES" '.cpu_cores // $cores' "$LATEST_RESULTS/system_info.json")
+    MEMORY_GB=$(jq -r --argjson memory "$MEMORY_GB" '.memory_gb // $memory' "$LATEST_RESULTS/system_info.json")
+fi
+
+# Per-suite means, kept for the per-signature costs of batched runs
+declare -A PROVING_MEANS VERIFICATION_MEANS GAS_MEANS ONCHAIN_GAS_MEANS
+
+# --- Start JSON generation ---
+echo "{" > "$SUMMARY_DIR/performance_data.json"
+echo "  \"instance_type\": \"$INSTANCE_TYPE\"," >> "$SUMMARY_DIR/performance_data.json"
+echo "  \"cpu_cores\": $CPU_CORES," >> "$SUMMARY_DIR/performance_data.json"
+echo "  \"memory_gb\": $MEMORY_GB," >> "$SUMMARY_DIR/performance_data.json"
+echo "  \"timestamp\": \"$(date -u +%Y-%m-%dT%H:%M:%SZ)\"," >> "$SUMMARY_DIR/performance_data.json"
+
+# Matrix runs (bench.py run) record the configuration they were run with
+if [ -f "$LATEST_RESULTS/config.json" ]; then
+    echo "  \"config\": $(jq -c '.' "$LATEST_RESULTS/config.json")," >> "$SUMMARY_DIR/performance_data.json"
+fi
+
+# --- Environment Metadata ---
+echo "  \"metadata\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_metadata=$(mktemp)
+if [ -f "$LATEST_RESULTS/system_info.json" ]; then
+    host_metadata=$(jq -c '.' "$LATEST_RESULTS/system_info.json" 2>/dev/null)
+    if [ -n "$host_metadata" ]; then
+        echo "    \"host\": $host_metadata" >> "$temp_entries_metadata"
+    fi
+fi
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark browser; do
+    metadata_file="$LATEST_RESULTS/$suite/benchmarks/metadata.json"
+    if [ -f "$metadata_file" ]; then
+        metadata=$(jq -c '.' "$metadata_file" 2>/dev/null)
+        if [ -n "$metadata" ]; then
+            echo "    \"$suite\": $metadata" >> "$temp_entries_metadata"
+        fi
+    fi
+done
+if [ -s "$temp_entries_metadata" ]; then
+    sed '$!s/$/,/' "$temp_entries_metadata" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_metadata"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Witness Generation ---
+# Reported apart from proving since on client devices it can dominate end-to-end time.
+# Cached witnesses (bench.py witnesses) carry the timing of the run that generated them.
+echo "  \"witness_times\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_witness=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu; do
+    if [ -f "$LATEST_RESULTS/$suite/benchmarks/all_witnesses_benchmark.json" ]; then
+        avg_time=$(jq -r '[.results[].mean] | add / length' "$LATEST_RESULTS/$suite/benchmarks/all_witnesses_benchmark.json" 2>/dev/null)
+        if [ -n "$avg_time" ] && [ "$avg_time" != "null" ]; then
+            echo "    \"$suite\": $avg_time" >> "$temp_entries_witness"
+        fi
+    fi
+done
+if [ -s "$temp_entries_witness" ]; then
+    sed '$!s/$/,/' "$temp_entries_witness" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_witness"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+echo "  \"witness_generation\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_witness_generation=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu; do
+    metadata_file="$LATEST_RESULTS/$suite/benchmarks/metadata.json"
+    if [ -f "$metadata_file" ] && [ "$(jq 'has("witness_generator")' "$metadata_file" 2>/dev/null)" = "true" ]; then
+        echo "    \"$suite\": $(jq -c '{generator: .witness_generator, cached: (.witnesses_cached // false)}' "$metadata_file")" >> "$temp_entries_witness_generation"
+    fi
+done
+if [ -s "$temp_entries_witness_generation" ]; then
+    sed '$!s/$/,/' "$temp_entries_witness_generation" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_witness_generation"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Proving Times ---
+echo "  \"proving_times\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_proving=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    if [ -f "$LATEST_RESULTS/$suite/benchmarks/all_proofs_benchmark.json" ]; then
+        avg_time=$(jq -r '[.results[].mean] | add / length' "$LATEST_RESULTS/$suite/benchmarks/all_proofs_benchmark.json" 2>/dev/null)
+        if [ -n "$avg_time" ] && [ "$avg_time" != "null" ]; then
+            echo "    \"$suite\": $avg_time" >> "$temp_entries_proving"
+            PROVING_MEANS[$suite]=$avg_time
+        fi
+    fi
+done
+if [ -s "$temp_entries_proving" ]; then
+    sed '$!s/$/,/' "$temp_entries_proving" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_proving"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Verification Times ---
+echo "  \"verification_times\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_verification=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    if [ -f "$LATEST_RESULTS/$suite/benchmarks/all_verifications_benchmark.json" ]; then
+        avg_time=$(jq -r '[.results[].times[]] | add / length' "$LATEST_RESULTS/$suite/benchmarks/all_verifications_benchmark.json" 2>/dev/null)
+        if [ -n "$avg_time" ] && [ "$avg_time" != "null" ]; then
+            echo "    \"$suite\": $avg_time" >> "$temp_entries_verification"
+            VERIFICATION_MEANS[$suite]=$avg_time
+        fi
+    fi
+done
+if [ -s "$temp_entries_verification" ]; then
+    sed '$!s/$/,/' "$temp_entries_verification" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_verification"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Verification Medians ---
+echo "  \"verification_medians\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_verification_median=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    if [ -f "$LATEST_RESULTS/$suite/benchmarks/all_verifications_benchmark.json" ]; then
+        median_time=$(jq -r "$JQ_DEFS"'[.results[].times[]] | median' "$LATEST_RESULTS/$suite/benchmarks/all_verifications_benchmark.json" 2>/dev/null)
+        if [ -n "$median_time" ] && [ "$median_time" != "null" ]; then
+            echo "    \"$suite\": $median_time" >> "$temp_entries_verification_median"
+        fi
+    fi
+done
+if [ -s "$temp_entries_verification_median" ]; then
+    sed '$!s/$/,/' "$temp_entries_verification_median" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_verification_median"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Timing Statistics ---
+echo "  \"timing_stats\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_stats=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    phase_entries=()
+    for phase in witnesses proofs verifications; do
+        bench_file="$LATEST_RESULTS/$suite/benchmarks/all_${phase}_benchmark.json"
+        if [ -f "$bench_file" ]; then
+            stats=$(jq -c "$STATS_FILTER" "$bench_file" 2>/dev/null)
+            if [ -n "$stats" ] && [ "$stats" != "null" ]; then
+                phase_entries+=("\"$phase\": $stats")
+            fi
+        fi
+    done
+    if [ ${#phase_entries[@]} -gt 0 ]; then
+        echo "    \"$suite\": {$(IFS=','; echo "${phase_entries[*]}")}" >> "$temp_entries_stats"
+    fi
+done
+if [ -s "$temp_entries_stats" ]; then
+    sed '$!s/$/,/' "$temp_entries_stats" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_stats"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Thread Scaling ---
+echo "  \"thread_scaling\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_scaling=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    scaling_file="$LATEST_RESULTS/$suite/benchmarks/thread_scaling.json"
+    if [ -f "$scaling_file" ]; then
+        scaling=$(jq -c '[.results[] | {threads, mean, speedup}]' "$scaling_file" 2>/dev/null)
+        if [ -n "$scaling" ]; then
+            echo "    \"$suite\": $scaling" >> "$temp_entries_scaling"
+        fi
+    fi
+done
+if [ -s "$temp_entries_scaling" ]; then
+    sed '$!s/$/,/' "$temp_entries_scaling" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_scaling"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Browser Proving ---
+echo "  \"browser_proving\": {" >> "$SUMMARY_DIR/performance_data.json"
+browser_file="$LATEST_RESULTS/browser/benchmarks/browser_benchmark.json"
+if [ -f "$browser_file" ]; then
+    browser_entries=$(jq -r "$JQ_DEFS"'.results | group_by(.stack) | map(
+        (map(select(.error == null))) as $ok |
+        ([$ok[].times[]]) as $times |
+        ([$ok[].witness_times[]]) as $witness_times |
+        "    \"\(.[0].stack)\": " + ({
+            test_cases: length,
+            failed: (length - ($ok | length)),
+            mean: (if ($times | length) > 0 then $times | add / length else null end),
+            median: ($times | median),
+            witness_mean: (if ($witness_times | length) > 0 then $witness_times | add / length else null end),
+            peak_rss_bytes: ([.[].peak_rss_bytes | select(. != null)] | max)
+        } | tojson)
+    ) | join(",\n")' "$browser_file" 2>/dev/null)
+    if [ -n "$browser_entries" ]; then
+        echo "$browser_entries" >> "$SUMMARY_DIR/performance_data.json"
+    fi
+fi
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Gas Costs ---
+echo "  \"gas_costs\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_gas=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+     gas_file_snarkjs_rapidsnark="$LATEST_RESULTS/$suite/gas-reports/reports/all_gas_data.json"
+     gas_file_noir="$LATEST_RESULTS/$suite/gas/gas_benchmark_summary.json"
+     avg_gas=""
+     if [ -f "$gas_file_snarkjs_rapidsnark" ]; then
+        avg_gas=$(jq -r '[.results[].mean] | add / length' "$gas_file_snarkjs_rapidsnark" 2>/dev/null)
+     elif [ -f "$gas_file_noir" ]; then
+        avg_gas=$(jq -r '[.results[].gas_used] | add / length' "$gas_file_noir" 2>/dev/null)
+     fi
+     if [ -n "$avg_gas" ] && [ "$avg_gas" != "null" ]; then
+        echo "    \"$suite\": $avg_gas" >> "$temp_entries_gas"
+        GAS_MEANS[$suite]=$avg_gas
+     fi
+done
+if [ -s "$temp_entries_gas" ]; then
+    sed '$!s/$/,/' "$temp_entries_gas" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_gas"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- On-chain Verification ---
+# Whole-transaction gas and calldata size of submitting each proof to the
+# verifier deployed on a local chain (onchain-verification.sh)
+echo "  \"onchain_verification\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_onchain=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    onchain_file="$LATEST_RESULTS/$suite/benchmarks/onchain_verification.json"
+    if [ -f "$onchain_file" ]; then
+        onchain=$(jq -c '{
+            verifier,
+            gas_used: ([.results[].gas_used] | add / length),
+            calldata_bytes: ([.results[].calldata_bytes] | add / length),
+            deployment_gas,
+            bytecode_bytes,
+            exceeds_code_size_limit
+        }' "$onchain_file" 2>/dev/null)
+        if [ -n "$onchain" ]; then
+            echo "    \"$suite\": $onchain" >> "$temp_entries_onchain"
+            ONCHAIN_GAS_MEANS[$suite]=$(echo "$onchain" | jq '.gas_used')
+        fi
+    fi
+done
+if [ -s "$temp_entries_onchain" ]; then
+    sed '$!s/$/,/' "$temp_entries_onchain" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_onchain"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Amortized Costs ---
+# Test cases generated with --batch-size prove several signatures at once, so
+# the per-proof means are also reported divided by the signatures per proof
+echo "  \"amortized\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_amortized=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    if [ -n "${PROVING_MEANS[$suite]}" ]; then
+        batch_size=$(jq '.batch_size // 1' "$LATEST_RESULTS/$suite/benchmarks/metadata.json" 2>/dev/null || echo 1)
+        amortized=$(jq -nc \
+            --argjson batch_size "${batch_size:-1}" \
+            --argjson proving "${PROVING_MEANS[$suite]}" \
+            --argjson verification "${VERIFICATION_MEANS[$suite]:-null}" \
+            --argjson gas "${GAS_MEANS[$suite]:-null}" \
+            --argjson onchain_gas "${ONCHAIN_GAS_MEANS[$suite]:-null}" \
+            '{
+                batch_size: $batch_size,
+                proving_time: ($proving / $batch_size),
+                verification_time: (if $verification == null then null else $verification / $batch_size end),
+                gas: (if $gas == null then null else $gas / $batch_size end),
+                onchain_gas: (if $onchain_gas == null then null else $onchain_gas / $batch_size end)
+            }')
+        echo "    \"$suite\": $amortized" >> "$temp_entries_amortized"
+    fi
+done
+if [ -s "$temp_entries_amortized" ]; then
+    sed '$!s/$/,/' "$temp_entries_amortized" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_amortized"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Artifact Sizes ---
+echo "  \"artifact_sizes\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_sizes=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    sizes_file="$LATEST_RESULTS/$suite/benchmarks/artifact_sizes.json"
+    if [ -f "$sizes_file" ]; then
+        sizes=$(jq -c '{
+            proving_key_bytes,
+            verification_key_bytes,
+            srs_bytes,
+            proof_bytes: ([.results[].proof_bytes] | add / length),
+            public_inputs_bytes: ([.results[].public_inputs_bytes] | add / length)
+        }' "$sizes_file" 2>/dev/null)
+        if [ -n "$sizes" ]; then
+            echo "    \"$suite\": $sizes" >> "$temp_entries_sizes"
+        fi
+    fi
+done
+if [ -s "$temp_entries_sizes" ]; then
+    sed '$!s/$/,/' "$temp_entries_sizes" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_sizes"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Soundness ---
+echo "  \"soundness\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_entries_soundness=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    soundness_file="$LATEST_RESULTS/$suite/benchmarks/soundness.json"
+    if [ -f "$soundness_file" ]; then
+        soundness=$(jq -c '{
+            invalid_cases: (.results | length),
+            rejected: ([.results[] | select(.rejected)] | length)
+        }' "$soundness_file" 2>/dev/null)
+        if [ -n "$soundness" ]; then
+            echo "    \"$suite\": $soundness" >> "$temp_entries_soundness"
+        fi
+    fi
+done
+if [ -s "$temp_entries_soundness" ]; then
+    sed '$!s/$/,/' "$temp_entries_soundness" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_entries_soundness"
+echo "  }," >> "$SUMMARY_DIR/performance_data.json"
+
+# --- Raw Data ---
+echo "  \"raw_data\": {" >> "$SUMMARY_DIR/performance_data.json"
+temp_raw_entries=$(mktemp)
+for suite in snarkjs rapidsnark rapidsnark-gpu noir gnark; do
+    if [ -d "$LATEST_RESULTS/$suite" ]; then
+        echo "    \"$suite\": {" >> "$temp_raw_entries"
+
+        # Raw proving times
+        proving_times_raw=$(jq -r '[.results[].mean] | map(select(. != null)) | join(",")' "$LATEST_RESULTS/$suite/benchmarks/all_proofs_benchmark.json" 2>/dev/null || echo "")
+        echo "      \"proving_times\": [$proving_times_raw]," >> "$temp_raw_entries"
+
+        # Raw verification times
+        verification_times_raw=$(jq -r '[.results[].mean] | map(select(. != null)) | join(",")' "$LATEST_RESULTS/$suite/benchmarks/all_verifications_benchmark.json" 2>/dev/null || echo "")
+        echo "      \"verification_times\": [$verification_times_raw]," >> "$temp_raw_entries"
+
+        # Raw proof sizes
+        proof_sizes_raw=$(jq -r '[.results[].proof_bytes] | map(select(. != null)) | join(",")' "$LATEST_RESULTS/$suite/benchmarks/artifact_sizes.json" 2>/dev/null || echo "")
+        echo "      \"proof_sizes\": [$proof_sizes_raw]," >> "$temp_raw_entries"
+
+        # Raw gas costs
+        gas_file_snarkjs_rapidsnark="$LATEST_RESULTS/$suite/gas-reports/reports/all_gas_data.json"
+        gas_file_noir="$LATEST_RESULTS/$suite/gas/gas_benchmark_summary.json"
+        gas_costs_raw=""
+        if [ -f "$gas_file_snarkjs_rapidsnark" ]; then
+            gas_costs_raw=$(jq -r '[.results[].mean] | map(select(. != null)) | join(",")' "$gas_file_snarkjs_rapidsnark" 2>/dev/null || echo "")
+        elif [ -f "$gas_file_noir" ]; then
+            gas_costs_raw=$(jq -r '[.results[].gas_used] | map(select(. != null)) | join(",")' "$gas_file_noir" 2>/dev/null || echo "")
+        fi
+        echo "      \"gas_costs\": [$gas_costs_raw]" >> "$temp_raw_entries"
+
+        echo "    }" >> "$temp_raw_entries"
+    fi
+done
+if [ -s "$temp_raw_entries" ]; then
+    sed '$!s/^\(    }\)$/\1,/' "$temp_raw_entries" >> "$SUMMARY_DIR/performance_data.json"
+fi
+rm "$temp_raw_entries"
+echo "  }" >> "$SUMMARY_DIR/performance_data.json"
+
+echo "}" >> "$SUMMARY_DIR/performance_data.json"
+# --- End JSON generation ---
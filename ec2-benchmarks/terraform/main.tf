@@ -38,6 +38,18 @@ variable "test_cases" {
   default     = 10
 }
 
+variable "include_invalid" {
+  description = "Also generate invalid test cases for soundness checks"
+  type        = bool
+  default     = false
+}
+
+variable "batch_size" {
+  description = "Signatures per test case, all verified in one proof"
+  type        = number
+  default     = 1
+}
+
 # Security group for benchmark instances
 resource "aws_security_group" "benchmark_sg" {
   name_prefix = "zk-benchmark-"
@@ -122,6 +134,8 @@ locals {
   user_data = base64encode(templatefile("${path.module}/user_data.sh", {
     volume_device_name = "/dev/nvme1n1"
     test_cases = var.test_cases
+    include_invalid = var.include_invalid
+    batch_size = var.batch_size
   }))
 }
 
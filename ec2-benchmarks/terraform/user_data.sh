@@ -171,7 +171,7 @@ source /home/ubuntu/.cargo/env
 cargo build --release
 
 # Generate test cases for benchmarking
-cargo run --bin generate_test_cases -- --num-test-cases=${test_cases}
+cargo run --bin generate_test_cases -- --num-test-cases=${test_cases} --batch-size=${batch_size}%{ if include_invalid } --include-invalid%{ endif }
 EOF
 
 # Get instance metadata
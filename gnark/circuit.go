@@ -43,3 +43,19 @@ func (circuit *ECDSACircuit) Define(api frontend.API) error {
 
 	return nil
 }
+
+// BatchECDSACircuit verifies several independent signatures in one proof, so
+// the proving cost is amortized over the batch
+type BatchECDSACircuit struct {
+	Signatures []ECDSACircuit
+}
+
+// Define declares the constraints of every signature in the batch
+func (circuit *BatchECDSACircuit) Define(api frontend.API) error {
+	for i := range circuit.Signatures {
+		if err := circuit.Signatures[i].Define(api); err != nil {
+			return err
+		}
+	}
+	return nil
+}
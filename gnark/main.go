@@ -33,6 +33,21 @@ type TestCase struct {
 	PubKeyY string `json:"pubkey_y"`
 }
 
+// TestCaseBatch is a gnark test case file generated with --batch-size, holding
+// one hex string per signature for each value
+type TestCaseBatch struct {
+	R       []string `json:"r"`
+	S       []string `json:"s"`
+	MsgHash []string `json:"msghash"`
+	PubKeyX []string `json:"pubkey_x"`
+	PubKeyY []string `json:"pubkey_y"`
+}
+
+// Manifest is the subset of tests/manifest.json the prover needs
+type Manifest struct {
+	BatchSize int `json:"batch_size"`
+}
+
 var (
 	// command line flags
 	outputDir string
@@ -78,11 +93,15 @@ func main() {
 func compileCircuit() {
 	fmt.Println("Compiling ECDSA circuit...")
 
-	// Create circuit instance
-	var circuit ECDSACircuit
+	// Create circuit instance, sized to the batch when test cases hold several signatures
+	var circuit frontend.Circuit = &ECDSACircuit{}
+	if n := batchSize(); n > 1 {
+		fmt.Printf("Batching %d signatures per proof\n", n)
+		circuit = &BatchECDSACircuit{Signatures: make([]ECDSACircuit, n)}
+	}
 
 	// Compile the circuit
-	ccs, err := frontend.Compile(ecc.BN254.ScalarField(), r1cs.NewBuilder, &circuit)
+	ccs, err := frontend.Compile(ecc.BN254.ScalarField(), r1cs.NewBuilder, circuit)
 	if err != nil {
 		log.Fatal("Circuit compilation failed:", err)
 	}
@@ -325,7 +344,77 @@ func loadTestCase(filename string) (*TestCase, error) {
 	return &testCase, nil
 }
 
+// batchSize returns the number of signatures per test case file recorded in
+// tests/manifest.json (1 when there is no manifest or it predates batching)
+func batchSize() int {
+	data, err := os.ReadFile(filepath.Join("tests", "manifest.json"))
+	if err != nil {
+		return 1
+	}
+	var manifest Manifest
+	if err := json.Unmarshal(data, &manifest); err != nil || manifest.BatchSize < 1 {
+		return 1
+	}
+	return manifest.BatchSize
+}
+
+// loadWitness builds the full witness for a test case file, batched or not
+func loadWitness(testCaseFile string) (witness.Witness, error) {
+	n := batchSize()
+	if n == 1 {
+		testCase, err := loadTestCase(testCaseFile)
+		if err != nil {
+			return nil, err
+		}
+		return createWitness(testCase)
+	}
+
+	data, err := os.ReadFile(testCaseFile)
+	if err != nil {
+		return nil, err
+	}
+	var batch TestCaseBatch
+	if err := json.Unmarshal(data, &batch); err != nil {
+		return nil, err
+	}
+	if len(batch.R) != n || len(batch.S) != n || len(batch.MsgHash) != n || len(batch.PubKeyX) != n || len(batch.PubKeyY) != n {
+		return nil, fmt.Errorf("test case batch must hold %d signatures", n)
+	}
+
+	assignment := BatchECDSACircuit{Signatures: make([]ECDSACircuit, n)}
+	for i := 0; i < n; i++ {
+		signature, err := assignmentFor(&TestCase{
+			R:       batch.R[i],
+			S:       batch.S[i],
+			MsgHash: batch.MsgHash[i],
+			PubKeyX: batch.PubKeyX[i],
+			PubKeyY: batch.PubKeyY[i],
+		})
+		if err != nil {
+			return nil, fmt.Errorf("signature %d: %v", i+1, err)
+		}
+		assignment.Signatures[i] = *signature
+	}
+	return frontend.NewWitness(&assignment, ecc.BN254.ScalarField())
+}
+
 func createWitness(testCase *TestCase) (witness.Witness, error) {
+	assignment, err := assignmentFor(testCase)
+	if err != nil {
+		return nil, err
+	}
+
+	// Create witness
+	witness, err := frontend.NewWitness(assignment, ecc.BN254.ScalarField())
+	if err != nil {
+		return nil, err
+	}
+
+	return witness, nil
+}
+
+// assignmentFor parses a test case into a circuit assignment
+func assignmentFor(testCase *TestCase) (*ECDSACircuit, error) {
 	// Parse hex strings to big integers
 	r, err := parseHexToBigInt(testCase.R)
 	if err != nil {
@@ -353,21 +442,13 @@ func createWitness(testCase *TestCase) (witness.Witness, error) {
 	}
 
 	// Create circuit assignment with emulated field elements
-	assignment := ECDSACircuit{
+	return &ECDSACircuit{
 		R:       emulated.ValueOf[emulated.P256Fr](r),
 		S:       emulated.ValueOf[emulated.P256Fr](s),
 		MsgHash: emulated.ValueOf[emulated.P256Fr](msgHash),
 		PubKeyX: emulated.ValueOf[emulated.P256Fp](pubKeyX),
 		PubKeyY: emulated.ValueOf[emulated.P256Fp](pubKeyY),
-	}
-
-	// Create witness
-	witness, err := frontend.NewWitness(&assignment, ecc.BN254.ScalarField())
-	if err != nil {
-		return nil, err
-	}
-
-	return witness, nil
+	}, nil
 }
 
 func createPublicWitness(testCase *TestCase) (witness.Witness, error) {
@@ -398,6 +479,20 @@ func parseHexToBigInt(hexStr string) (*big.Int, error) {
 	return bigInt, nil
 }
 
+// testCaseID extracts the identifier used in artifact names from a test case
+// filename: "3" for test_case_3.json and "invalid_3" for invalid_case_3.json
+func testCaseID(testCaseFile string) string {
+	baseName := filepath.Base(testCaseFile)
+	match := regexp.MustCompile(`(test|invalid)_case_(\d+)\.json`).FindStringSubmatch(baseName)
+	if match == nil {
+		log.Fatal("Invalid test case filename format")
+	}
+	if match[1] == "invalid" {
+		return "invalid_" + match[2]
+	}
+	return match[2]
+}
+
 func generateSingleProof(testCaseFile string) {
 	// Load constraint system
 	ccs := groth16.NewCS(ecc.BN254)
@@ -423,14 +518,8 @@ func generateSingleProof(testCaseFile string) {
 		log.Fatal("Failed to read proving key:", err)
 	}
 
-	// Load test case
-	testCase, err := loadTestCase(testCaseFile)
-	if err != nil {
-		log.Fatal("Failed to load test case:", err)
-	}
-
-	// Create witness
-	witness, err := createWitness(testCase)
+	// Load test case and create witness
+	witness, err := loadWitness(testCaseFile)
 	if err != nil {
 		log.Fatal("Failed to create witness:", err)
 	}
@@ -442,13 +531,7 @@ func generateSingleProof(testCaseFile string) {
 	}
 
 	// Extract test case number from filename
-	baseName := filepath.Base(testCaseFile)
-	testCaseNum := ""
-	if match := regexp.MustCompile(`test_case_(\d+)\.json`).FindStringSubmatch(baseName); match != nil {
-		testCaseNum = match[1]
-	} else {
-		log.Fatal("Invalid test case filename format")
-	}
+	testCaseNum := testCaseID(testCaseFile)
 
 	// Save proof
 	proofFile := filepath.Join(outputDir, "proof_"+testCaseNum+".groth16")
@@ -462,6 +545,22 @@ func generateSingleProof(testCaseFile string) {
 		log.Fatal("Failed to write proof:", err)
 	}
 
+	// Save public witness so its size can be reported alongside the proof
+	publicWitness, err := witness.Public()
+	if err != nil {
+		log.Fatal("Failed to extract public witness:", err)
+	}
+	publicFile := filepath.Join(outputDir, "public_"+testCaseNum+".wtns")
+	pf, err := os.Create(publicFile)
+	if err != nil {
+		log.Fatal("Failed to create public witness file:", err)
+	}
+	defer pf.Close()
+	_, err = publicWitness.WriteTo(pf)
+	if err != nil {
+		log.Fatal("Failed to write public witness:", err)
+	}
+
 	fmt.Printf("✓ Proof generated for test case %s\n", testCaseNum)
 }
 
@@ -479,22 +578,14 @@ func verifySingleProof(testCaseFile string) {
 	}
 
 	// Extract test case number from filename
-	baseName := filepath.Base(testCaseFile)
-	testCaseNum := ""
-	if match := regexp.MustCompile(`test_case_(\d+)\.json`).FindStringSubmatch(baseName); match != nil {
-		testCaseNum = match[1]
-	} else {
-		log.Fatal("Invalid test case filename format")
-	}
+	testCaseNum := testCaseID(testCaseFile)
 
 	// Load test case for public witness
-	testCase, err := loadTestCase(testCaseFile)
+	fullWitness, err := loadWitness(testCaseFile)
 	if err != nil {
-		log.Fatal("Failed to load test case:", err)
+		log.Fatal("Failed to create witness:", err)
 	}
-
-	// Create public witness
-	publicWitness, err := createPublicWitness(testCase)
+	publicWitness, err := fullWitness.Public()
 	if err != nil {
 		log.Fatal("Failed to create public witness:", err)
 	}
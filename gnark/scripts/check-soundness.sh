@@ -0,0 +1,70 @@
+#!/bin/bash
+
+set -e
+
+CYAN='\033[0;36m'
+GREEN='\033[0;32m'
+RED='\033[0;31m'
+NC='\033[0m'
+
+print_message() {
+  local color=$1
+  local message=$2
+  echo -e "${color}${message}${NC}"
+}
+
+print_message "$CYAN" "🛡️  Checking that invalid signatures are rejected..."
+
+# Ensure we're in the correct directory
+cd /app
+
+MANIFEST=tests/manifest.json
+
+# Invalid cases are only present when generated with --include-invalid
+if [ ! -f "$MANIFEST" ] || [ "$(jq '[.test_cases[] | select(.expected == "invalid")] | length' "$MANIFEST")" -eq 0 ]; then
+    print_message "$CYAN" "⏭️  No invalid test cases in $MANIFEST, skipping soundness check"
+    exit 0
+fi
+
+mkdir -p /out/benchmarks
+
+RESULTS=()
+ACCEPTED=0
+while read -r name corruption; do
+    # gnark's ECDSA gadget asserts validity, so proving should fail;
+    # verification is checked in case it doesn't
+    if ! go run main.go circuit.go prove -d /out "tests/${name}.json" > /dev/null 2>&1; then
+        rejected_at="prove"
+    elif ! go run main.go circuit.go verify -d /out "tests/${name}.json" > /dev/null 2>&1; then
+        rejected_at="verify"
+    else
+        rejected_at=""
+    fi
+
+    if [ -n "$rejected_at" ]; then
+        print_message "$GREEN" "✅ $name (corrupted $corruption) rejected at $rejected_at"
+        RESULTS+=("{\"test_case\": \"$name\", \"corruption\": \"$corruption\", \"rejected\": true, \"rejected_at\": \"$rejected_at\"}")
+    else
+        print_message "$RED" "❌ $name (corrupted $corruption) was ACCEPTED"
+        RESULTS+=("{\"test_case\": \"$name\", \"corruption\": \"$corruption\", \"rejected\": false, \"rejected_at\": null}")
+        ACCEPTED=$((ACCEPTED + 1))
+    fi
+done < <(jq -r '.test_cases[] | select(.expected == "invalid") | "\(.name) \(.corruption)"' "$MANIFEST")
+
+cat > /out/benchmarks/soundness.json << EOF
+{
+  "all_rejected": $([ $ACCEPTED -eq 0 ] && echo true || echo false),
+  "results": [
+    $(IFS=','; echo "${RESULTS[*]}")
+  ]
+}
+EOF
+
+print_message "$GREEN" "✅ Soundness results saved to /out/benchmarks/soundness.json"
+
+if [ $ACCEPTED -gt 0 ]; then
+    print_message "$RED" "❌ $ACCEPTED invalid test case(s) were accepted!"
+    exit 1
+else
+    print_message "$GREEN" "✅ All invalid test cases were rejected"
+fi
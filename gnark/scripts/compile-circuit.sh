@@ -18,6 +18,12 @@ cd /app
 
 print_message "$CYAN" "🔨 [1/4] Compiling circuit..."
 
+# gnark's setup is random, so a resumed run keeps the keys its earlier proofs were made with
+if [ "${RESUME:-0}" = "1" ] && [ -f "/out/circuit.r1cs" ] && [ -f "/out/proving.key" ] && [ -f "/out/verifying.key" ]; then
+    print_message "$GREEN" "♻️  Resuming with the circuit and keys already in /out"
+    exit 0
+fi
+
 # Compile the circuit and run setup
 print_message "$CYAN" "Compiling ECDSA circuit..."
 go run main.go circuit.go compile -d /out
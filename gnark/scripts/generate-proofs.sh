@@ -15,6 +15,10 @@ print_message() {
 
 print_message "$CYAN" "🔐 Generating proofs for all test cases..."
 
+# Timed runs and untimed warmup runs per test case (set by the benchmark runner)
+BENCH_RUNS=${BENCH_RUNS:-1}
+BENCH_WARMUP=${BENCH_WARMUP:-0}
+
 # Ensure we're in the correct directory
 cd /app
 
@@ -53,12 +57,12 @@ mkdir -p /out/benchmarks
 print_message "$CYAN" "🔄 Generating proofs..."
 TEST_CASES_LIST=$(printf "%s," "${TEST_CASE_NUMBERS[@]}" | sed 's/,$//')
 
-hyperfine --min-runs 1 --max-runs 1 \
-    -L test_case $TEST_CASES_LIST \
-    --show-output \
-    --export-json /out/benchmarks/all_proofs_benchmark.json \
-    --export-markdown /out/benchmarks/proofs_summary.md \
-    'go run main.go circuit.go prove -d /out tests/test_case_{test_case}.json'
+# Build once so compilation and linking aren't part of every timed proof
+go build -o /tmp/gnark-ecdsa main.go circuit.go
+
+/app/scripts/time-test-cases.sh proofs "$BENCH_RUNS" "$TEST_CASES_LIST" \
+    '/tmp/gnark-ecdsa prove -d /out tests/test_case_{test_case}.json' \
+    --show-output
 
 print_message "$GREEN" "✅ All proofs generated successfully!"
 
@@ -66,15 +70,16 @@ print_message "$GREEN" "✅ All proofs generated successfully!"
 print_message "$CYAN" ""
 print_message "$CYAN" "📈 Aggregate Statistics:"
 print_message "$CYAN" "----------------------------------------"
+print_message "$CYAN" "Runs per test case: $BENCH_RUNS (warmup: $BENCH_WARMUP)"
 
 if [ -f "/out/benchmarks/all_proofs_benchmark.json" ]; then
-    avg_time=$(jq -r '([.results[].mean | select(. != null)] | add) / ([.results[].mean | select(. != null)] | length)' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
-    min_time=$(jq -r '[.results[].min | select(. != null)] | min' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
-    max_time=$(jq -r '[.results[].max | select(. != null)] | max' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    avg_time=$(jq -r '[.results[].times[]] | add / length' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    min_time=$(jq -r '[.results[].times[]] | min' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    max_time=$(jq -r '[.results[].times[]] | max' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    median_time=$(jq -r -L /app/scripts 'include "stats"; [.results[].times[]] | median' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
     
     std_dev=$(jq -r '
-        .results | 
-        map(.mean) | 
+        [.results[].times[]] |
         (add / length) as $mean |
         map(($mean - .) * ($mean - .)) |
         (add / length) | 
@@ -83,6 +88,7 @@ if [ -f "/out/benchmarks/all_proofs_benchmark.json" ]; then
     
     if [[ "$avg_time" =~ ^[0-9]+\.?[0-9]*$ ]]; then
         printf "Average Time: %.3f ± %.3f seconds\n" $avg_time $std_dev
+        printf "Median Time: %.3f seconds\n" $median_time
         printf "Min Time: %.3f seconds\n" $min_time
         printf "Max Time: %.3f seconds\n" $max_time
     fi
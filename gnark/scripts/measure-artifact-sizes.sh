@@ -0,0 +1,97 @@
+#!/bin/bash
+
+set -e
+
+CYAN='\033[0;36m'
+GREEN='\033[0;32m'
+RED='\033[0;31m'
+NC='\033[0m'
+
+print_message() {
+  local color=$1
+  local message=$2
+  echo -e "${color}${message}${NC}"
+}
+
+print_message "$CYAN" "📏 Measuring artifact sizes for all test cases..."
+
+# Ensure we're in the correct directory
+cd /app
+
+# Check if circuit is compiled
+if [ ! -f "/out/proving.key" ] || [ ! -f "/out/verifying.key" ]; then
+    print_message "$RED" "Proving or verifying key not found. Please run compile-circuit.sh first."
+    exit 1
+fi
+
+# Discover test cases
+TEST_CASE_FILES=(tests/test_case_*.json)
+if [ ! -e "${TEST_CASE_FILES[0]}" ]; then
+    print_message "$RED" "No test case files found in tests directory!"
+    exit 1
+fi
+
+# Extract test case numbers and sort them
+TEST_CASE_NUMBERS=()
+for file in "${TEST_CASE_FILES[@]}"; do
+    if [[ $file =~ test_case_([0-9]+)\.json ]]; then
+        TEST_CASE_NUMBERS+=(${BASH_REMATCH[1]})
+    fi
+done
+
+# Sort the test case numbers
+IFS=$'\n' TEST_CASE_NUMBERS=($(sort -n <<<"${TEST_CASE_NUMBERS[*]}"))
+unset IFS
+
+NUM_TEST_CASES=${#TEST_CASE_NUMBERS[@]}
+print_message "$CYAN" "🔍 Discovered $NUM_TEST_CASES test cases: ${TEST_CASE_NUMBERS[*]}"
+
+mkdir -p /out/benchmarks
+
+# Size of a file in bytes, or null if it doesn't exist
+file_size() {
+    if [ -f "$1" ]; then
+        stat -c %s "$1"
+    else
+        echo "null"
+    fi
+}
+
+PROVING_KEY_BYTES=$(file_size /out/proving.key)
+VERIFICATION_KEY_BYTES=$(file_size /out/verifying.key)
+
+RESULTS=()
+for test_case in "${TEST_CASE_NUMBERS[@]}"; do
+    PROOF_BYTES=$(file_size /out/proof_${test_case}.groth16)
+    PUBLIC_INPUTS_BYTES=$(file_size /out/public_${test_case}.wtns)
+
+    if [ "$PROOF_BYTES" = "null" ] || [ "$PUBLIC_INPUTS_BYTES" = "null" ]; then
+        print_message "$RED" "Missing proof artifacts for test case $test_case. Please run generate-proofs.sh first."
+        exit 1
+    fi
+
+    RESULTS+=("{\"test_case\": $test_case, \"proof_bytes\": $PROOF_BYTES, \"public_inputs_bytes\": $PUBLIC_INPUTS_BYTES}")
+done
+
+# gnark runs a circuit-specific (unsafe) Groth16 setup, so there is no SRS file
+cat > /out/benchmarks/artifact_sizes.json << EOF
+{
+  "proving_key_bytes": $PROVING_KEY_BYTES,
+  "verification_key_bytes": $VERIFICATION_KEY_BYTES,
+  "srs_bytes": null,
+  "results": [
+    $(IFS=','; echo "${RESULTS[*]}")
+  ]
+}
+EOF
+
+print_message "$GREEN" "✅ Artifact sizes saved to /out/benchmarks/artifact_sizes.json"
+
+print_message "$CYAN" ""
+print_message "$CYAN" "📈 Artifact Sizes:"
+print_message "$CYAN" "----------------------------------------"
+printf "Proving Key: %s bytes\n" $PROVING_KEY_BYTES
+printf "Verification Key: %s bytes\n" $VERIFICATION_KEY_BYTES
+printf "Average Proof: %.0f bytes\n" $(jq -r '[.results[].proof_bytes] | add / length' /out/benchmarks/artifact_sizes.json)
+printf "Average Public Inputs: %.0f bytes\n" $(jq -r '[.results[].public_inputs_bytes] | add / length' /out/benchmarks/artifact_sizes.json)
+print_message "$CYAN" "----------------------------------------"
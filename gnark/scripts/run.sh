@@ -27,8 +27,33 @@ print_message "$CYAN" "🔐 Generating proofs..."
 print_message "$CYAN" "🔍 Verifying proofs..."
 ./scripts/verify-proofs.sh
 
-# Phase 4: Benchmark gas usage
+# Phase 4: Measure artifact sizes
+print_message "$CYAN" "📏 Measuring artifact sizes..."
+./scripts/measure-artifact-sizes.sh
+
+# Phase 5: Benchmark gas usage
 print_message "$CYAN" "⛽ Benchmarking gas usage..."
 ./scripts/benchmark-gas.sh
 
-print_message "$GREEN" "✅ All done! Check the benchmarks and gas-reports directories for results." 
\ No newline at end of file
+# Phase 6: Check that invalid test cases are rejected
+print_message "$CYAN" "🛡️  Checking soundness..."
+# Accepting an invalid test case fails the run, after the remaining steps
+SOUNDNESS_FAILED=0
+./scripts/check-soundness.sh || SOUNDNESS_FAILED=1
+
+# Optional: Measure proving time across thread counts
+if [ "$THREAD_SCALING" = "1" ]; then
+    print_message "$CYAN" "🧵 Measuring thread scaling..."
+    ./scripts/thread-scaling.sh
+fi
+
+# Phase 7: Record hardware and toolchain versions alongside the results
+print_message "$CYAN" "🖥️  Collecting environment metadata..."
+./scripts/collect-metadata.sh
+
+if [ "$SOUNDNESS_FAILED" = "1" ]; then
+    print_message "$RED" "❌ The circuit accepted invalid test cases, see /out/benchmarks/soundness.json"
+    exit 1
+fi
+
+print_message "$GREEN" "✅ All done! Check the benchmarks and gas-reports directories for results." 
@@ -0,0 +1,85 @@
+#!/bin/bash
+
+set -e
+
+CYAN='\033[0;36m'
+GREEN='\033[0;32m'
+RED='\033[0;31m'
+NC='\033[0m'
+
+print_message() {
+  local color=$1
+  local message=$2
+  echo -e "${color}${message}${NC}"
+}
+
+print_message "$CYAN" "🧵 Measuring proving time across thread counts..."
+
+# Timed runs per thread count (set by the benchmark runner)
+BENCH_RUNS=${BENCH_RUNS:-1}
+
+# Ensure we're in the correct directory
+cd /app
+
+# Check if circuit is compiled
+if [ ! -f "/out/circuit.r1cs" ] || [ ! -f "/out/proving.key" ]; then
+    print_message "$RED" "Circuit not found. Please run compile-circuit.sh first."
+    exit 1
+fi
+
+# Proving cost doesn't depend on the signature being proved, so a single test
+# case is enough to measure scaling
+TEST_CASE_FILES=(tests/test_case_*.json)
+if [ ! -e "${TEST_CASE_FILES[0]}" ]; then
+    print_message "$RED" "No test case files found in tests directory!"
+    exit 1
+fi
+TEST_CASE_FILE=$(printf "%s\n" "${TEST_CASE_FILES[@]}" | sort -V | head -n1)
+
+# Thread counts to test: 1, 2, 4, 8 and all cores, capped at the core count
+CPU_CORES=$(nproc)
+THREAD_COUNTS=()
+for threads in 1 2 4 8 $CPU_CORES; do
+    if [ "$threads" -le "$CPU_CORES" ] && [[ ! " ${THREAD_COUNTS[*]} " =~ " $threads " ]]; then
+        THREAD_COUNTS+=($threads)
+    fi
+done
+THREAD_COUNTS_LIST=$(printf "%s," "${THREAD_COUNTS[@]}" | sed 's/,$//')
+
+print_message "$CYAN" "🔍 Using $(basename "$TEST_CASE_FILE") with thread counts: ${THREAD_COUNTS[*]} ($BENCH_RUNS runs each)"
+
+# Build once so compilation isn't part of the single-threaded timings
+go build -o /tmp/gnark-ecdsa main.go circuit.go
+
+# Go schedules goroutines across GOMAXPROCS threads; the prover is also pinned
+# to the first N cores so every stack is capped the same way
+mkdir -p /out/benchmarks
+
+hyperfine --runs "$BENCH_RUNS" \
+    -L threads $THREAD_COUNTS_LIST \
+    --export-json /out/benchmarks/thread_scaling_benchmark.json \
+    --export-markdown /out/benchmarks/thread_scaling_summary.md \
+    "GOMAXPROCS={threads} taskset -c 0-\$(({threads} - 1)) /tmp/gnark-ecdsa prove -d /out $TEST_CASE_FILE"
+
+# Speedup and parallel efficiency relative to the single-threaded run
+jq --argjson cores "$CPU_CORES" '{
+    cores: $cores,
+    results: (.results | (map(select(.parameters.threads == "1"))[0].mean) as $base | map({
+        threads: (.parameters.threads | tonumber),
+        mean,
+        median,
+        stddev,
+        min,
+        max,
+        speedup: ($base / .mean),
+        efficiency: ($base / .mean / (.parameters.threads | tonumber))
+    }))
+}' /out/benchmarks/thread_scaling_benchmark.json > /out/benchmarks/thread_scaling.json
+
+print_message "$GREEN" "✅ Thread scaling results saved to /out/benchmarks/thread_scaling.json"
+
+print_message "$CYAN" ""
+print_message "$CYAN" "📈 Thread Scaling:"
+print_message "$CYAN" "----------------------------------------"
+jq -r '.results[] | "\(.threads) threads: \(.mean * 1000 | round / 1000)s (speedup \(.speedup * 100 | round / 100)x)"' /out/benchmarks/thread_scaling.json
+print_message "$CYAN" "----------------------------------------"
@@ -0,0 +1,56 @@
+#!/bin/bash
+
+# Time COMMAND for each test case with hyperfine, as `hyperfine -L test_case`
+# would, and merge the results into /out/benchmarks/all_<NAME>_benchmark.json and
+# <NAME>_summary.md. Each test case's results are kept in /out/benchmarks/<NAME>/,
+# and with RESUME=1 (set by `bench.py run --resume`) test cases that already have
+# them are not timed again, so an interrupted run continues where it stopped.
+#
+# Usage: time-test-cases.sh NAME RUNS TEST_CASES COMMAND [HYPERFINE_OPTION...]
+#   TEST_CASES is a comma-separated list of test case numbers, and COMMAND refers
+#   to the current one as {test_case}
+
+# Exit on error
+set -e
+
+NAME=$1
+RUNS=$2
+TEST_CASES=$3
+COMMAND=$4
+shift 4
+
+BENCH_WARMUP=${BENCH_WARMUP:-0}
+PARTS_DIR="/out/benchmarks/$NAME"
+
+if [ "${RESUME:-0}" != "1" ]; then
+    rm -rf "$PARTS_DIR"
+fi
+mkdir -p "$PARTS_DIR"
+
+PARTS=()
+for test_case in ${TEST_CASES//,/ }; do
+    PART="$PARTS_DIR/test_case_$test_case.json"
+    PARTS+=("$PART")
+    if [ -f "$PART" ]; then
+        echo "⏭️  Test case $test_case was already timed, skipping"
+        continue
+    fi
+    # Written under another name first, so an interrupted run never leaves a partial result
+    hyperfine --warmup "$BENCH_WARMUP" --runs "$RUNS" \
+        -L test_case "$test_case" \
+        --export-json "$PART.tmp" \
+        "$@" \
+        "$COMMAND"
+    mv "$PART.tmp" "$PART"
+done
+
+jq -s '{results: [.[].results[]]}' "${PARTS[@]}" > "/out/benchmarks/all_${NAME}_benchmark.json"
+
+{
+    echo "| Command | Mean [s] | Min [s] | Max [s] | Relative |"
+    echo "|:---|---:|---:|---:|---:|"
+    jq -r '(.results | map(.mean) | min) as $fastest | .results[] |
+        [.command, .mean, (.stddev // 0), .min, .max, .mean / $fastest] | @tsv' \
+        "/out/benchmarks/all_${NAME}_benchmark.json" |
+        awk -F'\t' '{ printf "| `%s` | %.3f ± %.3f | %.3f | %.3f | %.2f |\n", $1, $2, $3, $4, $5, $6 }'
+} > "/out/benchmarks/${NAME}_summary.md"
@@ -18,6 +18,11 @@ print_message "$CYAN" "🔍 Verifying proofs for all test cases..."
 # Ensure we're in the correct directory
 cd /app
 
+# Number of timed verifications per proof (verification is fast, so a single
+# run is dominated by noise)
+VERIFY_RUNS=${VERIFY_RUNS:-10}
+BENCH_WARMUP=${BENCH_WARMUP:-0}
+
 # Check if verifying key exists
 if [ ! -f "/out/verifying.key" ]; then
     print_message "$RED" "Verifying key not found. Please run compile-circuit.sh first."
@@ -61,15 +66,15 @@ if [ ${#missing_proofs[@]} -gt 0 ]; then
 fi
 
 # Verify proofs with hyperfine benchmark  
-print_message "$CYAN" "🔄 Verifying proofs..."
+print_message "$CYAN" "🔄 Verifying proofs ($VERIFY_RUNS runs each)..."
 TEST_CASES_LIST=$(printf "%s," "${TEST_CASE_NUMBERS[@]}" | sed 's/,$//')
 
-hyperfine --min-runs 1 --max-runs 1 \
-    -L test_case $TEST_CASES_LIST \
-    --show-output \
-    --export-json /out/benchmarks/all_verifications_benchmark.json \
-    --export-markdown /out/benchmarks/verifications_summary.md \
-    'go run main.go circuit.go verify -d /out tests/test_case_{test_case}.json'
+# Build once so compilation and linking aren't part of every timed verification
+go build -o /tmp/gnark-ecdsa main.go circuit.go
+
+/app/scripts/time-test-cases.sh verifications "$VERIFY_RUNS" "$TEST_CASES_LIST" \
+    '/tmp/gnark-ecdsa verify -d /out tests/test_case_{test_case}.json' \
+    --show-output
 
 print_message "$GREEN" "✅ All proofs verified successfully!"
 
@@ -88,13 +93,21 @@ if [ -f "/out/benchmarks/all_verifications_benchmark.json" ]; then
     avg_time=$(jq -r '([.results[].mean | select(. != null)] | add) / ([.results[].mean | select(. != null)] | length)' /out/benchmarks/all_verifications_benchmark.json 2>/dev/null)
     min_time=$(jq -r '[.results[].min | select(. != null)] | min' /out/benchmarks/all_verifications_benchmark.json 2>/dev/null)
     max_time=$(jq -r '[.results[].max | select(. != null)] | max' /out/benchmarks/all_verifications_benchmark.json 2>/dev/null)
+    median_time=$(jq -r -L /app/scripts 'include "stats"; [.results[].times[]] | median' /out/benchmarks/all_verifications_benchmark.json 2>/dev/null)
     
+    if [ "$median_time" = "null" ]; then
+        print_message "$RED" "No verification timings recorded"
+        exit 1
+    fi
+
     # Convert to milliseconds for display (like rapidsnark)
     avg_time_ms=$(echo "$avg_time * 1000" | bc -l)
+    median_time_ms=$(echo "$median_time * 1000" | bc -l)
     min_time_ms=$(echo "$min_time * 1000" | bc -l)
     max_time_ms=$(echo "$max_time * 1000" | bc -l)
     
     printf "Average Time: %.1f ms\n" $avg_time_ms
+    printf "Median Time: %.1f ms\n" $median_time_ms
     printf "Min Time: %.1f ms\n" $min_time_ms
     printf "Max Time: %.1f ms\n" $max_time_ms
 fi
@@ -0,0 +1,83 @@
+#!/bin/bash
+
+set -e
+
+CYAN='\033[0;36m'
+GREEN='\033[0;32m'
+RED='\033[0;31m'
+NC='\033[0m'
+
+print_message() {
+  local color=$1
+  local message=$2
+  echo -e "${color}${message}${NC}"
+}
+
+# Source Barretenberg environment if it exists
+if [ -f "$HOME/.bb/env" ]; then
+  source "$HOME/.bb/env"
+fi
+
+print_message "$CYAN" "🛡️  Checking that invalid signatures are rejected..."
+
+# Get the absolute path to the noir project directory (where Nargo.toml is located)
+NOIR_DIR="$(dirname "$0")/.."
+NOIR_DIR="$(cd "$NOIR_DIR" && pwd)"
+MANIFEST="$NOIR_DIR/tests/manifest.json"
+CIRCUIT_FILE="/out/compilation/benchmarking.json"
+
+# Invalid cases are only present when generated with --include-invalid
+if [ ! -f "$MANIFEST" ] || [ "$(jq '[.test_cases[] | select(.expected == "invalid")] | length' "$MANIFEST")" -eq 0 ]; then
+    print_message "$CYAN" "⏭️  No invalid test cases in $MANIFEST, skipping soundness check"
+    exit 0
+fi
+
+mkdir -p /tmp/soundness /out/benchmarks
+cd "$NOIR_DIR"
+
+RESULTS=()
+ACCEPTED=0
+while read -r name corruption; do
+    proof_dir="/tmp/soundness/$name"
+    mkdir -p "$proof_dir"
+
+    # The circuit asserts the signature is valid, so execution should fail;
+    # proving and verification are checked in case it doesn't
+    if ! nargo execute -p "tests/${name}.toml" "${name}_witness" > /dev/null 2>&1; then
+        rejected_at="witness"
+    elif ! bb prove -b "$CIRCUIT_FILE" -w "target/${name}_witness.gz" -o "$proof_dir" --oracle_hash keccak --output_format bytes_and_fields > /dev/null 2>&1; then
+        rejected_at="prove"
+    elif ! bb write_vk -b "$CIRCUIT_FILE" -o "$proof_dir" --oracle_hash keccak > /dev/null 2>&1 || \
+         ! bb verify -k "$proof_dir/vk" -p "$proof_dir/proof" -i "$proof_dir/public_inputs" --oracle_hash keccak > /dev/null 2>&1; then
+        rejected_at="verify"
+    else
+        rejected_at=""
+    fi
+
+    if [ -n "$rejected_at" ]; then
+        print_message "$GREEN" "✅ $name (corrupted $corruption) rejected at $rejected_at"
+        RESULTS+=("{\"test_case\": \"$name\", \"corruption\": \"$corruption\", \"rejected\": true, \"rejected_at\": \"$rejected_at\"}")
+    else
+        print_message "$RED" "❌ $name (corrupted $corruption) was ACCEPTED"
+        RESULTS+=("{\"test_case\": \"$name\", \"corruption\": \"$corruption\", \"rejected\": false, \"rejected_at\": null}")
+        ACCEPTED=$((ACCEPTED + 1))
+    fi
+done < <(jq -r '.test_cases[] | select(.expected == "invalid") | "\(.name) \(.corruption)"' "$MANIFEST")
+
+cat > /out/benchmarks/soundness.json << EOF
+{
+  "all_rejected": $([ $ACCEPTED -eq 0 ] && echo true || echo false),
+  "results": [
+    $(IFS=','; echo "${RESULTS[*]}")
+  ]
+}
+EOF
+
+print_message "$GREEN" "✅ Soundness results saved to /out/benchmarks/soundness.json"
+
+if [ $ACCEPTED -gt 0 ]; then
+    print_message "$RED" "❌ $ACCEPTED invalid test case(s) were accepted!"
+    exit 1
+else
+    print_message "$GREEN" "✅ All invalid test cases were rejected"
+fi
@@ -35,6 +35,15 @@ cd "$NOIR_DIR"
 print_message "$CYAN" "Working in directory: $(pwd)"
 print_message "$CYAN" "Checking for Nargo.toml: $(ls -la Nargo.toml)"
 
+# Test cases generated with --batch-size hold several signatures per file, so the
+# batched entry point replaces src/main.nr (in the container's copy of the project)
+# with BATCH_SIZE set to match; later nargo runs such as the soundness check reuse it
+BATCH_SIZE=$(jq '.batch_size // 1' "$TESTS_DIR/manifest.json" 2>/dev/null || echo 1)
+if [ "$BATCH_SIZE" -gt 1 ]; then
+  print_message "$CYAN" "📦 Batching $BATCH_SIZE signatures per proof"
+  sed "s/^global BATCH_SIZE: u32 = 1;/global BATCH_SIZE: u32 = $BATCH_SIZE;/" batch/main.nr > src/main.nr
+fi
+
 # Step 1: Compile the circuit
 print_message "$CYAN" "📝 Compiling the Noir circuit..."
 nargo compile
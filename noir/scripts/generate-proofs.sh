@@ -31,6 +31,10 @@ fi
 
 print_message "$CYAN" "🔐 Starting proof generation for Noir ECDSA test cases..."
 
+# Timed runs and untimed warmup runs per test case (set by the benchmark runner)
+BENCH_RUNS=${BENCH_RUNS:-1}
+BENCH_WARMUP=${BENCH_WARMUP:-1}
+
 # Create persistent output directories
 mkdir -p /out/proofs
 mkdir -p /out/benchmarks
@@ -95,12 +99,9 @@ chmod +x /tmp/generate_single_proof.sh
 print_message "$CYAN" "🔄 Generating proofs..."
 TEST_CASES_LIST=$(printf "%s," "${TEST_CASE_NUMBERS[@]}" | sed 's/,$//')
 
-hyperfine --warmup 1 --min-runs 1 --max-runs 1 \
-    -L test_case $TEST_CASES_LIST \
-    --show-output \
-    --export-json /out/benchmarks/all_proofs_benchmark.json \
-    --export-markdown /out/benchmarks/proofs_summary.md \
-    '/tmp/generate_single_proof.sh {test_case}'
+/app/scripts/time-test-cases.sh proofs "$BENCH_RUNS" "$TEST_CASES_LIST" \
+    '/tmp/generate_single_proof.sh {test_case}' \
+    --show-output
 
 print_message "$GREEN" "✅ All proofs generated successfully!"
 
@@ -108,15 +109,16 @@ print_message "$GREEN" "✅ All proofs generated successfully!"
 print_message "$CYAN" ""
 print_message "$CYAN" "📈 Aggregate Statistics:"
 print_message "$CYAN" "----------------------------------------"
+print_message "$CYAN" "Runs per test case: $BENCH_RUNS (warmup: $BENCH_WARMUP)"
 
 if [ -f "/out/benchmarks/all_proofs_benchmark.json" ]; then
-    avg_time=$(jq -r '([.results[].mean | select(. != null)] | add) / ([.results[].mean | select(. != null)] | length)' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
-    min_time=$(jq -r '[.results[].min | select(. != null)] | min' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
-    max_time=$(jq -r '[.results[].max | select(. != null)] | max' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    avg_time=$(jq -r '[.results[].times[]] | add / length' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    min_time=$(jq -r '[.results[].times[]] | min' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    max_time=$(jq -r '[.results[].times[]] | max' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
+    median_time=$(jq -r -L /app/scripts 'include "stats"; [.results[].times[]] | median' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null)
     
     std_dev=$(jq -r '
-        .results | 
-        map(.mean) | 
+        [.results[].times[]] |
         (add / length) as $mean |
         map(($mean - .) * ($mean - .)) |
         (add / length) | 
@@ -125,6 +127,7 @@ if [ -f "/out/benchmarks/all_proofs_benchmark.json" ]; then
     
     if [[ "$avg_time" =~ ^[0-9]+\.?[0-9]*$ ]]; then
         printf "Average Time: %.3f ± %.3f seconds\n" $avg_time $std_dev
+        printf "Median Time: %.3f seconds\n" $median_time
         printf "Min Time: %.3f seconds\n" $min_time
         printf "Max Time: %.3f seconds\n" $max_time
     fi
@@ -0,0 +1,88 @@
+#!/bin/bash
+
+set -e
+
+CYAN='\033[0;36m'
+GREEN='\033[0;32m'
+RED='\033[0;31m'
+NC='\033[0m'
+
+print_message() {
+  local color=$1
+  local message=$2
+  echo -e "${color}${message}${NC}"
+}
+
+print_message "$CYAN" "📏 Measuring artifact sizes for Noir ECDSA proofs..."
+
+mkdir -p /out/benchmarks
+
+# Check if proofs exist
+TOTAL_PROOFS=$(find /out/proofs -name "test_case_*" -type d 2>/dev/null | wc -l)
+if [ "$TOTAL_PROOFS" -eq 0 ]; then
+    print_message "$RED" "❌ No proof directories found in /out/proofs"
+    print_message "$RED" "   Please run the proof generation step first."
+    exit 1
+fi
+
+# Size of a file in bytes, or null if it doesn't exist
+file_size() {
+  if [ -f "$1" ]; then
+    stat -c %s "$1"
+  else
+    echo "null"
+  fi
+}
+
+TEST_CASE_NUMBERS=()
+for proof_dir in /out/proofs/test_case_*; do
+  if [ -d "$proof_dir" ]; then
+    BASENAME=$(basename "$proof_dir")
+    TEST_CASE_NUMBERS+=("${BASENAME#test_case_}")
+  fi
+done
+
+IFS=$'\n' TEST_CASE_NUMBERS=($(sort -n <<<"${TEST_CASE_NUMBERS[*]}"))
+unset IFS
+
+# UltraHonk has no persistent proving key; bb derives it from the circuit and
+# the downloaded CRS on every prove, so the CRS stands in for the SRS size.
+FIRST_PROOF_DIR="/out/proofs/test_case_${TEST_CASE_NUMBERS[0]}"
+VERIFICATION_KEY_BYTES=$(file_size "$FIRST_PROOF_DIR/vk")
+SRS_BYTES=$(file_size "$HOME/.bb-crs/bn254_g1.dat")
+
+RESULTS=()
+for test_case in "${TEST_CASE_NUMBERS[@]}"; do
+  PROOF_DIR="/out/proofs/test_case_${test_case}"
+  PROOF_BYTES=$(file_size "$PROOF_DIR/proof")
+  PUBLIC_INPUTS_BYTES=$(file_size "$PROOF_DIR/public_inputs")
+
+  if [ "$PROOF_BYTES" = "null" ] || [ "$PUBLIC_INPUTS_BYTES" = "null" ]; then
+    print_message "$RED" "❌ Missing proof artifacts for test_case_${test_case}"
+    print_message "$RED" "   Expected: $PROOF_DIR/proof and $PROOF_DIR/public_inputs"
+    exit 1
+  fi
+
+  RESULTS+=("{\"test_case\": $test_case, \"proof_bytes\": $PROOF_BYTES, \"public_inputs_bytes\": $PUBLIC_INPUTS_BYTES}")
+done
+
+cat > /out/benchmarks/artifact_sizes.json << EOF
+{
+  "proving_key_bytes": null,
+  "verification_key_bytes": $VERIFICATION_KEY_BYTES,
+  "srs_bytes": $SRS_BYTES,
+  "results": [
+    $(IFS=','; echo "${RESULTS[*]}")
+  ]
+}
+EOF
+
+print_message "$GREEN" "✅ Artifact sizes saved to /out/benchmarks/artifact_sizes.json"
+
+print_message "$CYAN" "📈 Artifact Sizes:"
+echo "----------------------------------------"
+printf "Verification Key: %s bytes\n" $VERIFICATION_KEY_BYTES
+printf "SRS: %s bytes\n" $SRS_BYTES
+printf "Average Proof: %.0f bytes\n" $(jq -r '[.results[].proof_bytes] | add / length' /out/benchmarks/artifact_sizes.json)
+printf "Average Public Inputs: %.0f bytes\n" $(jq -r '[.results[].public_inputs_bytes] | add / length' /out/benchmarks/artifact_sizes.json)
+echo "----------------------------------------"
@@ -0,0 +1,112 @@
+#!/bin/bash
+
+set -e
+
+CYAN='\033[0;36m'
+GREEN='\033[0;32m'
+YELLOW='\033[1;33m'
+RED='\033[0;31m'
+NC='\033[0m'
+
+print_message() {
+  local color=$1
+  local message=$2
+  echo -e "${color}${message}${NC}"
+}
+
+# Deploys the honk Solidity verifier built by benchmark-gas.sh to a local anvil
+# chain and submits every proof to it in a transaction. Unlike the forge gas
+# report, the gas recorded here is what a user pays: the whole transaction,
+# including the 21000 base cost and the calldata.
+
+print_message "$CYAN" "⛓️  Measuring on-chain verification..."
+
+FOUNDRY_PROJECT=/out/gas/gas-benchmark
+if [ ! -f "$FOUNDRY_PROJECT/src/NoirVerifier.sol" ]; then
+  print_message "$RED" "❌ Solidity verifier not found, run benchmark-gas.sh first"
+  exit 1
+fi
+
+mkdir -p /out/benchmarks
+
+# anvil's first prefunded development account
+RPC_URL=http://127.0.0.1:8545
+PRIVATE_KEY=0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80
+# Contracts larger than EIP-170's limit cannot be deployed on mainnet, but are
+# still deployed here so their verification gas can be measured
+CODE_SIZE_LIMIT=24576
+
+anvil --silent --disable-code-size-limit &
+ANVIL_PID=$!
+trap 'kill $ANVIL_PID 2>/dev/null || true' EXIT
+for _ in $(seq 50); do
+  cast block-number --rpc-url "$RPC_URL" > /dev/null 2>&1 && break
+  sleep 0.2
+done
+
+# Receipts report gas as hex in newer foundry versions
+to_dec() {
+  case "$1" in
+    0x*) cast to-dec "$1" ;;
+    *) echo "$1" ;;
+  esac
+}
+
+cd "$FOUNDRY_PROJECT"
+# Older foundry versions broadcast by default and have no --broadcast flag
+BROADCAST=""
+if forge create --help | grep -q -- '--broadcast'; then
+  BROADCAST=--broadcast
+fi
+print_message "$CYAN" "🚀 Deploying HonkVerifier..."
+DEPLOYMENT=$(forge create src/NoirVerifier.sol:HonkVerifier \
+  --rpc-url "$RPC_URL" --private-key "$PRIVATE_KEY" $BROADCAST --json)
+VERIFIER=$(echo "$DEPLOYMENT" | jq -r '.deployedTo')
+DEPLOYMENT_GAS=$(to_dec "$(cast receipt --json "$(echo "$DEPLOYMENT" | jq -r '.transactionHash')" --rpc-url "$RPC_URL" | jq -r '.gasUsed')")
+BYTECODE_BYTES=$(cast codesize "$VERIFIER" --rpc-url "$RPC_URL")
+print_message "$CYAN" "   Deployed at $VERIFIER ($BYTECODE_BYTES bytes, $DEPLOYMENT_GAS gas)"
+if [ "$BYTECODE_BYTES" -gt "$CODE_SIZE_LIMIT" ]; then
+  print_message "$YELLOW" "⚠️  The verifier exceeds the $CODE_SIZE_LIMIT byte contract size limit"
+fi
+
+RESULTS=()
+for proof_dir in $(ls -d /out/proofs/test_case_* | sort -V); do
+  TEST_NUMBER=$(basename "$proof_dir")
+  TEST_NUMBER=${TEST_NUMBER#test_case_}
+
+  # Public inputs are field elements, passed as bytes32
+  PUBLIC_INPUTS=$(jq -r '.[]' "$proof_dir/public_inputs_fields.json" | while read -r value; do
+    cast to-uint256 "$value"
+  done | paste -sd, -)
+  CALLDATA=$(cast calldata "verify(bytes,bytes32[])" "0x$(xxd -p "$proof_dir/proof" | tr -d '\n')" "[$PUBLIC_INPUTS]")
+  CALLDATA_BYTES=$(( (${#CALLDATA} - 2) / 2 ))
+
+  # The honk verifier reverts on an invalid proof
+  if ! cast call "$VERIFIER" "$CALLDATA" --rpc-url "$RPC_URL" > /dev/null; then
+    print_message "$RED" "❌ The on-chain verifier rejected the proof for test case $TEST_NUMBER"
+    exit 1
+  fi
+
+  RECEIPT=$(cast send "$VERIFIER" "$CALLDATA" --rpc-url "$RPC_URL" --private-key "$PRIVATE_KEY" --json)
+  GAS_USED=$(to_dec "$(echo "$RECEIPT" | jq -r '.gasUsed')")
+  print_message "$GREEN" "   Test case $TEST_NUMBER: $GAS_USED gas, $CALLDATA_BYTES bytes of calldata"
+  RESULTS+=("{\"test_case\": $TEST_NUMBER, \"gas_used\": $GAS_USED, \"calldata_bytes\": $CALLDATA_BYTES}")
+done
+
+jq -n \
+  --arg verifier HonkVerifier \
+  --argjson deployment_gas "$DEPLOYMENT_GAS" \
+  --argjson bytecode_bytes "$BYTECODE_BYTES" \
+  --argjson code_size_limit "$CODE_SIZE_LIMIT" \
+  --argjson results "[$(IFS=,; echo "${RESULTS[*]}")]" \
+  '{
+    verifier: $verifier,
+    deployment_gas: $deployment_gas,
+    bytecode_bytes: $bytecode_bytes,
+    exceeds_code_size_limit: ($bytecode_bytes > $code_size_limit),
+    results: $results
+  }' > /out/benchmarks/onchain_verification.json
+
+print_message "$GREEN" "✅ On-chain verification results saved to /out/benchmarks/onchain_verification.json"
+jq -r '"   Average: \([.results[].gas_used] | add / length | round) gas, \([.results[].calldata_bytes] | add / length | round) bytes of calldata"' \
+  /out/benchmarks/onchain_verification.json
@@ -45,20 +45,49 @@ fi
 SCRIPT_DIR="$(dirname "$0")"
 
 # Step 1: Compile circuit and generate witnesses
-print_message "$CYAN" "🔨 [1/4] Compiling circuit and generating witnesses..."
+print_message "$CYAN" "🔨 [1/8] Compiling circuit and generating witnesses..."
 bash "$SCRIPT_DIR/compile-and-generate-witness.sh"
 
 # Step 2: Generate proofs
-print_message "$CYAN" "🔐 [2/4] Generating proofs..."
+print_message "$CYAN" "🔐 [2/8] Generating proofs..."
 bash "$SCRIPT_DIR/generate-proofs.sh"
 
 # Step 3: Verify proofs
-print_message "$CYAN" "🔍 [3/4] Verifying proofs..."
+print_message "$CYAN" "🔍 [3/8] Verifying proofs..."
 bash "$SCRIPT_DIR/verify-proofs.sh"
 
-# Step 4: Benchmark gas usage
-print_message "$CYAN" "⛽ [4/4] Benchmarking gas usage..."
+# Step 4: Measure artifact sizes
+print_message "$CYAN" "📏 [4/8] Measuring artifact sizes..."
+bash "$SCRIPT_DIR/measure-artifact-sizes.sh"
+
+# Step 5: Benchmark gas usage
+print_message "$CYAN" "⛽ [5/8] Benchmarking gas usage..."
 bash "$SCRIPT_DIR/benchmark-gas.sh"
 
+# Step 6: Deploy the verifier to a local chain and record gas and calldata per verification
+print_message "$CYAN" "⛓️  [6/8] Measuring on-chain verification..."
+bash "$SCRIPT_DIR/onchain-verification.sh"
+
+# Step 7: Check that invalid test cases are rejected
+print_message "$CYAN" "🛡️  [7/8] Checking soundness..."
+# Accepting an invalid test case fails the run, after the remaining steps
+SOUNDNESS_FAILED=0
+bash "$SCRIPT_DIR/check-soundness.sh" || SOUNDNESS_FAILED=1
+
+# Optional: Measure proving time across thread counts
+if [ "$THREAD_SCALING" = "1" ]; then
+  print_message "$CYAN" "🧵 Measuring thread scaling..."
+  bash "$SCRIPT_DIR/thread-scaling.sh"
+fi
+
+# Step 8: Record hardware and toolchain versions alongside the results
+print_message "$CYAN" "🖥️  [8/8] Collecting environment metadata..."
+bash "$SCRIPT_DIR/collect-metadata.sh"
+
+if [ "$SOUNDNESS_FAILED" = "1" ]; then
+  print_message "$RED" "❌ The circuit accepted invalid test cases, see /out/benchmarks/soundness.json"
+  exit 1
+fi
+
 print_message "$GREEN" "✅ All Noir ECDSA benchmark steps completed successfully!"
-print_message "$GREEN" "📁 Check the /out directory for all artifacts and benchmarks." 
\ No newline at end of file
+print_message "$GREEN" "📁 Check the /out directory for all artifacts and benchmarks." 
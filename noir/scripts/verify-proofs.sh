@@ -30,8 +30,14 @@ fi
 
 print_message "$CYAN" "🔍 Starting proof verification for Noir ECDSA test cases..."
 
+# Number of timed verifications per proof (verification is fast, so a single
+# run is dominated by noise)
+VERIFY_RUNS=${VERIFY_RUNS:-10}
+BENCH_WARMUP=${BENCH_WARMUP:-0}
+
 # Create persistent output directories
 mkdir -p /out/verification
+mkdir -p /out/benchmarks
 
 # Check if proofs exist
 if [ ! -d "/out/proofs" ] || [ -z "$(find /out/proofs -name 'proof' -type f)" ]; then
@@ -51,120 +57,98 @@ fi
 print_message "$CYAN" "📊 Found $TOTAL_PROOFS test cases to verify"
 
 # Check if verification has already been completed
-VERIFICATION_SUMMARY="/out/verification/verification_summary.json"
-if [ -f "$VERIFICATION_SUMMARY" ]; then
-    COMPLETED_VERIFICATIONS=$(jq -r '.results | length' "$VERIFICATION_SUMMARY" 2>/dev/null || echo "0")
+BENCHMARK_FILE="/out/benchmarks/all_verifications_benchmark.json"
+if [ -f "$BENCHMARK_FILE" ]; then
+    COMPLETED_VERIFICATIONS=$(jq -r '.results | length' "$BENCHMARK_FILE" 2>/dev/null || echo "0")
     if [ "$COMPLETED_VERIFICATIONS" -eq "$TOTAL_PROOFS" ]; then
         print_message "$GREEN" "✅ All proofs already verified, skipping verification step."
-        print_message "$GREEN" "   Found: $VERIFICATION_SUMMARY with $COMPLETED_VERIFICATIONS results"
-        print_message "$GREEN" "   To re-verify, delete the '/out/verification' directory first."
+        print_message "$GREEN" "   Found: $BENCHMARK_FILE with $COMPLETED_VERIFICATIONS results"
+        print_message "$GREEN" "   To re-verify, delete it first."
         exit 0
     fi
 fi
 
-CURRENT_TEST=0
-VERIFICATION_RESULTS=()
-
-# Find all proof directories and verify each one
+TEST_CASE_NUMBERS=()
 for proof_dir in /out/proofs/test_case_*; do
   if [ -d "$proof_dir" ]; then
-    CURRENT_TEST=$((CURRENT_TEST + 1))
     BASENAME=$(basename "$proof_dir")
-    
-    PROOF_FILE="$proof_dir/proof"
-    VK_FILE="$proof_dir/vk"
-    
+
     # Check if required files exist
-    if [ ! -f "$PROOF_FILE" ] || [ ! -f "$VK_FILE" ]; then
-        print_message "$RED" "❌ [$CURRENT_TEST/$TOTAL_PROOFS] Missing files for $BASENAME"
-        print_message "$RED" "   Expected: $PROOF_FILE and $VK_FILE"
-        exit 1
-    fi
-    
-    print_message "$CYAN" "🔍 [$CURRENT_TEST/$TOTAL_PROOFS] Verifying proof for $BASENAME..."
-    
-    # Change to the proof directory for verification
-    cd "$proof_dir"
-    
-    # Measure verification time
-    START_TIME=$(date +%s.%N)
-    
-    # Verify the proof
-    if bb verify -k vk -p proof -i public_inputs --oracle_hash keccak; then
-        END_TIME=$(date +%s.%N)
-        VERIFICATION_TIME=$(echo "$END_TIME - $START_TIME" | bc -l)
-        
-        print_message "$GREEN" "✅ [$CURRENT_TEST/$TOTAL_PROOFS] Proof for $BASENAME verified successfully (${VERIFICATION_TIME}s)"
-        
-        # Store result for summary
-        VERIFICATION_RESULTS+=("{\"test_case\":\"$BASENAME\",\"verification_time\":$VERIFICATION_TIME,\"status\":\"success\"}")
-    else
-        print_message "$RED" "❌ [$CURRENT_TEST/$TOTAL_PROOFS] Proof verification failed for $BASENAME"
+    if [ ! -f "$proof_dir/proof" ] || [ ! -f "$proof_dir/vk" ]; then
+        print_message "$RED" "❌ Missing files for $BASENAME"
+        print_message "$RED" "   Expected: $proof_dir/proof and $proof_dir/vk"
         exit 1
     fi
-    
-    # Return to root directory
-    cd /app
+
+    TEST_CASE_NUMBERS+=("${BASENAME#test_case_}")
   fi
 done
 
-# Generate verification summary
-print_message "$CYAN" "📊 Generating verification summary..."
-
-# Create JSON summary
-cat > "$VERIFICATION_SUMMARY" << EOF
-{
-  "total_test_cases": $TOTAL_PROOFS,
-  "timestamp": "$(date -u --iso-8601=seconds)",
-  "results": [
-    $(IFS=','; echo "${VERIFICATION_RESULTS[*]}")
-  ]
-}
+# Create proof verification script for hyperfine
+cat > /tmp/verify_single_proof.sh << 'EOF'
+#!/bin/bash
+set -e
+
+TEST_CASE=$1
+PROOF_DIR="/out/proofs/test_case_${TEST_CASE}"
+
+cd "$PROOF_DIR"
+
+bb verify -k vk -p proof -i public_inputs --oracle_hash keccak > /dev/null 2>&1
 EOF
 
+chmod +x /tmp/verify_single_proof.sh
+
+# Verify proofs with hyperfine benchmark
+print_message "$CYAN" "🔄 Verifying proofs ($VERIFY_RUNS runs each)..."
+TEST_CASES_LIST=$(printf "%s," "${TEST_CASE_NUMBERS[@]}" | sed 's/,$//')
+
+if ! /app/scripts/time-test-cases.sh verifications "$VERIFY_RUNS" "$TEST_CASES_LIST" \
+    '/tmp/verify_single_proof.sh {test_case}'; then
+    print_message "$RED" "❌ Proof verification failed"
+    exit 1
+fi
+
 # Calculate and display aggregate statistics
-if [ ${#VERIFICATION_RESULTS[@]} -gt 0 ]; then
-    print_message "$CYAN" "📈 Calculating aggregate statistics..."
-    
-    # Calculate average, min, max verification times
-    avg_time=$(jq -r '[.results[].verification_time] | add / length' "$VERIFICATION_SUMMARY")
-    min_time=$(jq -r '[.results[].verification_time] | min' "$VERIFICATION_SUMMARY")
-    max_time=$(jq -r '[.results[].verification_time] | max' "$VERIFICATION_SUMMARY")
-    std_dev=$(jq -r '
-        .results | 
-        map(.verification_time) | 
-        (add / length) as $mean |
-        map(($mean - .) * ($mean - .)) |
-        (add / length) | 
-        sqrt
-    ' "$VERIFICATION_SUMMARY")
-    
-    # Create summary report
-    cat > "/out/verification/verification_report.txt" << EOF
+print_message "$CYAN" "📈 Calculating aggregate statistics..."
+
+avg_time=$(jq -r '[.results[].times[]] | add / length' "$BENCHMARK_FILE")
+median_time=$(jq -r -L /app/scripts 'include "stats"; [.results[].times[]] | median' "$BENCHMARK_FILE")
+min_time=$(jq -r '[.results[].min] | min' "$BENCHMARK_FILE")
+max_time=$(jq -r '[.results[].max] | max' "$BENCHMARK_FILE")
+std_dev=$(jq -r '
+    [.results[].times[]] |
+    (add / length) as $mean |
+    map(($mean - .) * ($mean - .)) |
+    (add / length) |
+    sqrt
+' "$BENCHMARK_FILE")
+
+# Create summary report
+cat > "/out/verification/verification_report.txt" << EOF
 Noir ECDSA Proof Verification Report
 ====================================
 
 Total Test Cases: $TOTAL_PROOFS
+Runs Per Proof: $VERIFY_RUNS
 All Verifications: Successful
 
 Verification Time Statistics:
   Average: $(printf "%.6f" $avg_time) seconds
-  Minimum: $(printf "%.6f" $min_time) seconds  
+  Median:  $(printf "%.6f" $median_time) seconds
+  Minimum: $(printf "%.6f" $min_time) seconds
   Maximum: $(printf "%.6f" $max_time) seconds
   Std Dev: $(printf "%.6f" $std_dev) seconds
 
 Generated at: $(date -u --iso-8601=seconds)
 EOF
 
-    print_message "$GREEN" "✅ All proofs verified successfully!"
-    print_message "$GREEN" "📁 Verification results: /out/verification/"
-    print_message "$CYAN" "📈 Verification Statistics:"
-    echo "----------------------------------------"
-    printf "Average Time: %.6f ± %.6f seconds\n" $avg_time $std_dev
-    printf "Min Time: %.6f seconds\n" $min_time
-    printf "Max Time: %.6f seconds\n" $max_time
-    echo "----------------------------------------"
-else
-    print_message "$RED" "❌ No verification results to summarize"
-    exit 1
-fi
\ No newline at end of file
+print_message "$GREEN" "✅ All proofs verified successfully!"
+print_message "$GREEN" "📁 Verification results: /out/verification/ and $BENCHMARK_FILE"
+print_message "$CYAN" "📈 Verification Statistics:"
+echo "----------------------------------------"
+printf "Average Time: %.6f ± %.6f seconds\n" $avg_time $std_dev
+printf "Median Time: %.6f seconds\n" $median_time
+printf "Min Time: %.6f seconds\n" $min_time
+printf "Max Time: %.6f seconds\n" $max_time
+echo "----------------------------------------"
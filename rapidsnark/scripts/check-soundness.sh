@@ -0,0 +1,81 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+echo "🛡️  Checking that invalid signatures are rejected..."
+
+MANIFEST=./tests/manifest.json
+
+# Invalid cases are only present when generated with --include-invalid
+if [ ! -f "$MANIFEST" ] || [ "$(jq '[.test_cases[] | select(.expected == "invalid")] | length' "$MANIFEST")" -eq 0 ]; then
+    echo "⏭️  No invalid test cases in $MANIFEST, skipping soundness check"
+    exit 0
+fi
+
+mkdir -p /tmp/soundness /out/benchmarks
+
+# The GPU image proves with icicle-snark on CUDA instead of rapidsnark's CPU prover
+PROVER=/rapidsnark/package_noasm/bin/prover
+if [ "$GPU" = "1" ]; then
+    PROVER=/app/scripts/gpu-prover.sh
+fi
+
+# Same witness generator as compute-witnesses.sh
+generate_witness() {
+    if [ -x "/out/setup/circuit_cpp/circuit" ]; then
+        /out/setup/circuit_cpp/circuit "$1" "$2"
+    else
+        node /out/setup/circuit_js/generate_witness.js /out/setup/circuit_js/circuit.wasm "$1" "$2"
+    fi
+}
+
+RESULTS=()
+ACCEPTED=0
+while read -r name corruption; do
+    witness_file="/tmp/soundness/${name}.wtns"
+    proof_file="/tmp/soundness/proof_${name}.json"
+    public_file="/tmp/soundness/public_${name}.json"
+
+    # A bad signature must fail at some stage: witness generation (if the
+    # circuit constrains validity), proving, verification, or the circuit's
+    # result output signal (the first public signal) being 0
+    if ! generate_witness "./tests/${name}.json" "$witness_file" > /dev/null 2>&1; then
+        rejected_at="witness"
+    elif ! $PROVER /out/setup/circuit.zkey "$witness_file" "$proof_file" "$public_file" > /dev/null 2>&1; then
+        rejected_at="prove"
+    elif ! /rapidsnark/package_noasm/bin/verifier /out/setup/verification_key.json "$public_file" "$proof_file" > /dev/null 2>&1; then
+        rejected_at="verify"
+    elif [ "$(jq -r '.[0]' "$public_file")" = "0" ]; then
+        rejected_at="output"
+    else
+        rejected_at=""
+    fi
+
+    if [ -n "$rejected_at" ]; then
+        echo "✅ $name (corrupted $corruption) rejected at $rejected_at"
+        RESULTS+=("{\"test_case\": \"$name\", \"corruption\": \"$corruption\", \"rejected\": true, \"rejected_at\": \"$rejected_at\"}")
+    else
+        echo "❌ $name (corrupted $corruption) was ACCEPTED"
+        RESULTS+=("{\"test_case\": \"$name\", \"corruption\": \"$corruption\", \"rejected\": false, \"rejected_at\": null}")
+        ACCEPTED=$((ACCEPTED + 1))
+    fi
+done < <(jq -r '.test_cases[] | select(.expected == "invalid") | "\(.name) \(.corruption)"' "$MANIFEST")
+
+cat > /out/benchmarks/soundness.json << EOF
+{
+  "all_rejected": $([ $ACCEPTED -eq 0 ] && echo true || echo false),
+  "results": [
+    $(IFS=','; echo "${RESULTS[*]}")
+  ]
+}
+EOF
+
+echo "✅ Soundness results saved to /out/benchmarks/soundness.json"
+
+if [ $ACCEPTED -gt 0 ]; then
+    echo "❌ $ACCEPTED invalid test case(s) were accepted!"
+    exit 1
+else
+    echo "✅ All invalid test cases were rejected"
+fi
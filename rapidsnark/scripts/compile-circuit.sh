@@ -9,8 +9,51 @@ echo "🔨 [1/5] Compiling circuit..."
 mkdir -p /out/setup
 mkdir -p /out/benchmarks
 
+# circom-ecdsa-p256 only implements 6 chunks of 43 bits, which circuit.circom
+# and the batch circuit below are both built with
+CHUNK_BITS=$(jq '.chunk_layout.chunk_bits // 43' ./tests/manifest.json 2>/dev/null || echo 43)
+NUM_CHUNKS=$(jq '.chunk_layout.num_chunks // 6' ./tests/manifest.json 2>/dev/null || echo 6)
+if [ "$CHUNK_BITS" -ne 43 ] || [ "$NUM_CHUNKS" -ne 6 ]; then
+    echo "❌ Error: the test cases use $NUM_CHUNKS chunks of $CHUNK_BITS bits, but the circuit only supports 6 chunks of 43 bits"
+    echo "   Regenerate them without --num-chunks or --chunk-bits"
+    exit 1
+fi
+
+# Test cases generated with --batch-size hold several signatures per file and
+# need a circuit that verifies the whole batch in one proof
+BATCH_SIZE=$(jq '.batch_size // 1' ./tests/manifest.json 2>/dev/null || echo 1)
+CIRCUIT=circuit.circom
+if [ "$BATCH_SIZE" -gt 1 ]; then
+    CIRCUIT="$(mktemp -d)/circuit.circom"
+    cat > "$CIRCUIT" << EOF
+pragma circom 2.1.5;
+
+include "batch.circom";
+
+component main { public [msghash] } = BatchECDSAVerifyNoPubkeyCheck($CHUNK_BITS, $NUM_CHUNKS, $BATCH_SIZE);
+EOF
+    echo "📦 Batching $BATCH_SIZE signatures per proof ($NUM_CHUNKS chunks of $CHUNK_BITS bits)"
+fi
+
+# circom's C++ witness generator is much faster than the wasm one but relies on
+# x86-64 assembly, so other architectures (or WITNESS_GENERATOR=wasm) use wasm
+WITNESS_GENERATOR=${WITNESS_GENERATOR:-cpp}
+if [ "$WITNESS_GENERATOR" = "cpp" ] && [ "$(uname -m)" != "x86_64" ]; then
+    echo "⚠️  The C++ witness generator needs x86-64, falling back to wasm"
+    WITNESS_GENERATOR=wasm
+fi
+TARGETS="--wasm"
+if [ "$WITNESS_GENERATOR" = "cpp" ]; then
+    TARGETS="--wasm --c"
+fi
+
 # Compile the circuit
-circom circuit.circom --r1cs --wasm --sym -o /out/setup
+circom "$CIRCUIT" --r1cs $TARGETS --sym -l "$(pwd)" -o /out/setup
+
+if [ "$WITNESS_GENERATOR" = "cpp" ]; then
+    echo "🔧 Building the C++ witness generator..."
+    make -C /out/setup/circuit_cpp -j"$(nproc)"
+fi
 
 echo "✅ Circuit compiled successfully!"
 echo "   Artifacts saved to /out/setup/" 
\ No newline at end of file
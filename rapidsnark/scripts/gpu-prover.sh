@@ -0,0 +1,22 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+# Prove on a CUDA GPU with icicle-snark, taking the same arguments as rapidsnark's prover:
+#   gpu-prover.sh <circuit.zkey> <witness.wtns> <proof.json> <public.json>
+# icicle-snark reads its commands from stdin. Like rapidsnark, each proof is a fresh
+# process, so loading the proving key onto the device is part of the timed proof.
+if [ $# -ne 4 ]; then
+    echo "Usage: $0 <circuit.zkey> <witness.wtns> <proof.json> <public.json>" >&2
+    exit 1
+fi
+
+rm -f "$3" "$4"
+echo "prove --zkey $1 --witness $2 --proof $3 --public $4 --device CUDA" | /icicle-snark/target/release/icicle-snark
+
+# icicle-snark reports errors without a failing exit code
+if [ ! -s "$3" ] || [ ! -s "$4" ]; then
+    echo "❌ icicle-snark did not write a proof" >&2
+    exit 1
+fi
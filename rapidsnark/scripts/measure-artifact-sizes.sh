@@ -0,0 +1,90 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+echo "📏 Measuring artifact sizes..."
+
+mkdir -p /out/benchmarks
+
+# Check if trusted setup was completed
+if [ ! -f "/out/setup/circuit.zkey" ] || [ ! -f "/out/setup/verification_key.json" ]; then
+    echo "❌ Setup artifacts not found!"
+    echo "   Please run trusted-setup.sh first."
+    exit 1
+fi
+
+# Discover test cases from tests directory
+TEST_CASE_FILES=(./tests/test_case_*.json)
+if [ ! -e "${TEST_CASE_FILES[0]}" ]; then
+    echo "❌ No test case files found in tests directory!"
+    echo "   Expected files like: test_case_1.json, test_case_2.json, etc."
+    exit 1
+fi
+
+# Extract test case numbers and sort them
+TEST_CASE_NUMBERS=()
+for file in "${TEST_CASE_FILES[@]}"; do
+    # Extract number from filename (e.g., test_case_3.json -> 3)
+    if [[ $file =~ test_case_([0-9]+)\.json ]]; then
+        TEST_CASE_NUMBERS+=(${BASH_REMATCH[1]})
+    fi
+done
+
+# Sort the test case numbers
+IFS=$'\n' TEST_CASE_NUMBERS=($(sort -n <<<"${TEST_CASE_NUMBERS[*]}"))
+unset IFS
+
+NUM_TEST_CASES=${#TEST_CASE_NUMBERS[@]}
+echo "🔍 Discovered $NUM_TEST_CASES test cases: ${TEST_CASE_NUMBERS[*]}"
+
+# Size of a file in bytes, or null if it doesn't exist
+file_size() {
+    if [ -f "$1" ]; then
+        stat -c %s "$1"
+    else
+        echo "null"
+    fi
+}
+
+PROVING_KEY_BYTES=$(file_size /out/setup/circuit.zkey)
+VERIFICATION_KEY_BYTES=$(file_size /out/setup/verification_key.json)
+SRS_BYTES=$(file_size "${PTAU:-pot22_final.ptau}")
+
+# Collect per test case proof and public input sizes
+RESULTS=()
+for i in "${TEST_CASE_NUMBERS[@]}"; do
+    PROOF_BYTES=$(file_size /out/proofs/proof_${i}.json)
+    PUBLIC_INPUTS_BYTES=$(file_size /out/proofs/public_${i}.json)
+
+    if [ "$PROOF_BYTES" = "null" ] || [ "$PUBLIC_INPUTS_BYTES" = "null" ]; then
+        echo "❌ Missing proof artifacts for test case $i!"
+        echo "   Please run generate-proofs.sh first."
+        exit 1
+    fi
+
+    RESULTS+=("{\"test_case\": $i, \"proof_bytes\": $PROOF_BYTES, \"public_inputs_bytes\": $PUBLIC_INPUTS_BYTES}")
+done
+
+cat > /out/benchmarks/artifact_sizes.json << EOF
+{
+  "proving_key_bytes": $PROVING_KEY_BYTES,
+  "verification_key_bytes": $VERIFICATION_KEY_BYTES,
+  "srs_bytes": $SRS_BYTES,
+  "results": [
+    $(IFS=','; echo "${RESULTS[*]}")
+  ]
+}
+EOF
+
+echo "✅ Artifact sizes saved to /out/benchmarks/artifact_sizes.json"
+
+echo ""
+echo "📈 Artifact Sizes:"
+echo "----------------------------------------"
+printf "Proving Key: %s bytes\n" $PROVING_KEY_BYTES
+printf "Verification Key: %s bytes\n" $VERIFICATION_KEY_BYTES
+printf "SRS: %s bytes\n" $SRS_BYTES
+printf "Average Proof: %.0f bytes\n" $(jq -r '[.results[].proof_bytes] | add / length' /out/benchmarks/artifact_sizes.json)
+printf "Average Public Inputs: %.0f bytes\n" $(jq -r '[.results[].public_inputs_bytes] | add / length' /out/benchmarks/artifact_sizes.json)
+echo "----------------------------------------"
@@ -17,14 +17,17 @@ if [ -f "/out/setup/circuit.zkey" ] && [ -f "/out/setup/verification_key.json" ]
 fi
 
 # Check if powers of tau file exists (mounted from host)
-if [ ! -f "pot22_final.ptau" ]; then
+# Powers of tau to use; `bench.py setup` passes the smallest file that fits the circuit
+PTAU=${PTAU:-pot22_final.ptau}
+
+if [ ! -f "$PTAU" ]; then
     echo "❌ Error: Powers of tau file not found!"
     echo "Please ensure pot22_final.ptau is mounted into the container."
     echo "Download it with: curl -L 'https://storage.googleapis.com/zkevm/ptau/powersOfTau28_hez_final_22.ptau' -o pot22_final.ptau"
     exit 1
 fi
 
-echo "✅ Powers of tau file found, proceeding with setup."
+echo "✅ Powers of tau file $PTAU found, proceeding with setup."
 
 # Detect available memory and set appropriate Node.js heap size (cross-platform)
 if [ -n "$NODE_MEMORY_MB" ]; then
@@ -78,7 +81,7 @@ fi
 echo "📊 Detected ${TOTAL_MEM_MB}MB RAM, allocating ${NODE_MEMORY}MB to Node.js"
 
 echo "📝 Generating proving key and verification key..."
-NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY snarkjs groth16 setup /out/setup/circuit.r1cs pot22_final.ptau /out/setup/circuit.zkey
+NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY snarkjs groth16 setup /out/setup/circuit.r1cs "$PTAU" /out/setup/circuit.zkey
 
 # Export the verification key
 echo "🔑 Exporting verification key..."
@@ -5,6 +5,10 @@ set -e
 
 echo "🔍 [5/5] Verifying proofs..."
 
+# Number of timed verifications per proof (verification is fast, so a single
+# run is dominated by noise)
+VERIFY_RUNS=${VERIFY_RUNS:-10}
+BENCH_WARMUP=${BENCH_WARMUP:-0}
 
 # Discover test cases from tests directory
 TEST_CASE_FILES=(./tests/test_case_*.json)
@@ -33,14 +37,11 @@ echo "🔍 Discovered $NUM_TEST_CASES test cases: ${TEST_CASE_NUMBERS[*]}"
 # Generate test case list from discovered test cases
 TEST_CASES=$(printf "%s," "${TEST_CASE_NUMBERS[@]}" | sed 's/,$//')
 
-# Run hyperfine with parameter list for test cases
-echo "📊 Running benchmarks for $NUM_TEST_CASES test cases..."
-hyperfine --min-runs 1 --max-runs 1 \
-    -L test_case $TEST_CASES \
-    --show-output \
-    --export-json /out/benchmarks/all_verifications_benchmark.json \
-    --export-markdown /out/benchmarks/verifications_summary.md \
-    "bash -c 'snarkjs groth16 verify /out/setup/verification_key.json <(jq . /out/proofs/public_{test_case}.json) <(jq . /out/proofs/proof_{test_case}.json)'"
+# Time each test case with hyperfine
+echo "📊 Running benchmarks for $NUM_TEST_CASES test cases ($VERIFY_RUNS runs each)..."
+/app/scripts/time-test-cases.sh verifications "$VERIFY_RUNS" "$TEST_CASES" \
+    '/rapidsnark/package_noasm/bin/verifier /out/setup/verification_key.json /out/proofs/public_{test_case}.json /out/proofs/proof_{test_case}.json' \
+    --show-output
 
 echo "✅ All proofs verified successfully!"
 
@@ -71,6 +72,12 @@ if ! max_time=$(jq -r '[.results[].max | select(. != null)] | max' /out/benchmar
     exit 1
 fi
 
+# Median over every individual verification run
+if ! median_time=$(jq -r -L /app/scripts 'include "stats"; [.results[].times[]] | median' /out/benchmarks/all_verifications_benchmark.json 2>/dev/null); then
+    echo "Error: Could not calculate median time"
+    exit 1
+fi
+
 # Calculate standard deviation
 if ! std_dev=$(jq -r '
     .results | 
@@ -85,8 +92,9 @@ if ! std_dev=$(jq -r '
 fi
 
 # Only print if we have valid numbers
-if [[ "$avg_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$min_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$max_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$std_dev" =~ ^[0-9]+\.?[0-9]*$ ]]; then
+if [[ "$avg_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$median_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$min_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$max_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$std_dev" =~ ^[0-9]+\.?[0-9]*$ ]]; then
     printf "Average Time: %.3f ± %.3f seconds\n" $avg_time $std_dev
+    printf "Median Time: %.3f seconds\n" $median_time
     printf "Min Time: %.3f seconds\n" $min_time
     printf "Max Time: %.3f seconds\n" $max_time
 else
@@ -0,0 +1,144 @@
+use clap::Parser;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::time::Instant;
+
+/// Times a command over a list of parameter values and exports the results in
+/// hyperfine's JSON format, for devices (phones, ARM boards) without hyperfine
+#[derive(Parser, Debug)]
+#[command(author, version, about)]
+struct Args {
+    /// Untimed runs before the timed ones, per parameter value
+    #[arg(short, long, default_value_t = 0)]
+    warmup: usize,
+
+    /// Timed runs per parameter value
+    #[arg(short, long, default_value_t = 1)]
+    runs: usize,
+
+    /// Run the command once per comma-separated value, replacing {NAME} in it
+    #[arg(short = 'L', long, num_args = 2, value_names = ["NAME", "VALUES"])]
+    parameter_list: Option<Vec<String>>,
+
+    /// Write the timings to FILE as hyperfine's --export-json does
+    #[arg(long, value_name = "FILE")]
+    export_json: PathBuf,
+
+    /// Command to time, run with `sh -c`
+    command: String,
+}
+
+/// Run `command` once, exiting if it fails, and return its wall-clock time in seconds
+fn run_once(command: &str) -> f64 {
+    let start = Instant::now();
+    let status = Command::new("sh").arg("-c").arg(command).status().unwrap_or_else(|e| {
+        eprintln!("Failed to start `{}`: {}", command, e);
+        process::exit(1);
+    });
+    let elapsed = start.elapsed().as_secs_f64();
+    if !status.success() {
+        eprintln!("Command terminated with non-zero exit code {}: {}", status.code().unwrap_or(-1), command);
+        process::exit(1);
+    }
+    elapsed
+}
+
+/// One entry of hyperfine's `results` array
+fn result_entry(command: &str, times: &[f64], parameters: Value) -> Value {
+    let n = times.len() as f64;
+    let mean = times.iter().sum::<f64>() / n;
+    let mut sorted = times.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let median = if sorted.len() % 2 == 1 {
+        sorted[sorted.len() / 2]
+    } else {
+        (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2.0
+    };
+    // hyperfine reports the sample standard deviation, which needs two runs
+    let stddev = (times.len() > 1).then(|| (times.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt());
+    json!({
+        "command": command,
+        "mean": mean,
+        "stddev": stddev,
+        "median": median,
+        "min": sorted[0],
+        "max": sorted[sorted.len() - 1],
+        "times": times,
+        "exit_codes": vec![0; times.len()],
+        "parameters": parameters,
+    })
+}
+
+fn main() {
+    let args = Args::parse();
+
+    if args.runs == 0 {
+        eprintln!("--runs must be at least 1");
+        process::exit(1);
+    }
+
+    let benchmarks: Vec<(String, Value)> = match &args.parameter_list {
+        Some(list) => {
+            let (name, values) = (&list[0], &list[1]);
+            if values.split(',').any(str::is_empty) {
+                eprintln!("-L {} needs a comma-separated list of non-empty values, got '{}'", name, values);
+                process::exit(1);
+            }
+            values
+                .split(',')
+                .map(|value| (args.command.replace(&format!("{{{}}}", name), value), json!({ name: value })))
+                .collect()
+        }
+        None => vec![(args.command.clone(), json!({}))],
+    };
+
+    let mut results = Vec::new();
+    for (i, (command, parameters)) in benchmarks.iter().enumerate() {
+        println!("Benchmark {}: {}", i + 1, command);
+        for _ in 0..args.warmup {
+            run_once(command);
+        }
+        let times: Vec<f64> = (0..args.runs).map(|_| run_once(command)).collect();
+        let result = result_entry(command, &times, parameters.clone());
+        println!("  Time (mean): {:.3} s ({} runs)", result["mean"].as_f64().unwrap_or_default(), times.len());
+        results.push(result);
+    }
+
+    let output = serde_json::to_string_pretty(&json!({ "results": results })).expect("results serialize to JSON");
+    if let Err(e) = fs::write(&args.export_json, output) {
+        eprintln!("Failed to write {}: {}", args.export_json.display(), e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn result_entry_takes_the_middle_time_of_an_odd_number_of_runs() {
+        let result = result_entry("prove", &[3.0, 1.0, 2.0], json!({}));
+        assert_eq!(result["median"], 2.0);
+        assert_eq!(result["mean"], 2.0);
+        assert_eq!(result["stddev"], 1.0);
+        assert_eq!((result["min"].clone(), result["max"].clone()), (json!(1.0), json!(3.0)));
+        assert_eq!(result["times"], json!([3.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn result_entry_averages_the_middle_times_of_an_even_number_of_runs() {
+        let result = result_entry("prove", &[4.0, 1.0, 3.0, 2.0], json!({ "test_case": "1" }));
+        assert_eq!(result["median"], 2.5);
+        assert_eq!(result["exit_codes"], json!([0, 0, 0, 0]));
+        assert_eq!(result["parameters"], json!({ "test_case": "1" }));
+    }
+
+    #[test]
+    fn result_entry_has_no_stddev_for_a_single_run() {
+        let result = result_entry("prove", &[1.5], json!({}));
+        assert_eq!(result["median"], 1.5);
+        assert_eq!(result["stddev"], Value::Null);
+    }
+}
@@ -1,159 +1,70 @@
-use clap::Parser;
-use p256::ecdsa::{SigningKey, Signature, signature::Signer};
-use rand::rngs::OsRng;
-use serde::Serialize;
+use clap::{ArgGroup, Parser};
 use std::fs;
-use std::path::Path;
-use num_bigint::{BigUint, ToBigUint};
-use sha2::{Sha256, Digest};
+use std::path::{Path, PathBuf};
+use std::process;
+use testcase_gen::{import, webauthn, ChunkLayout, Corruption, EcdsaTestCase, HashFunction, Manifest, ManifestEntry, Stack};
 
 /// CLI Arguments
 #[derive(Parser, Debug)]
 #[command(author, version, about)]
+#[command(group(ArgGroup::new("signed_message").args(["message", "message_hash"])))]
 struct Args {
     /// Number of test cases to generate
     #[arg(short, long, default_value_t = 10)]
     num_test_cases: usize,
-}
 
-/// Test case data for snarkjs/rapidsnark
-#[derive(Serialize)]
-struct SnarkjsTestCase {
-    r: Vec<String>,
-    s: Vec<String>,
-    msghash: Vec<String>,
-    pubkey: Vec<Vec<String>>,
-}
+    /// Also generate invalid test cases (corrupted r, s, message hash, or mismatched pubkey)
+    /// that every stack is expected to reject
+    #[arg(long)]
+    include_invalid: bool,
 
-/// Test case data for gnark (uses hex strings)
-#[derive(Serialize)]
-struct GnarkTestCase {
-    r: String,
-    s: String,
-    msghash: String,
-    pubkey_x: String,
-    pubkey_y: String,
-}
+    /// Re-validate the existing test case files instead of generating new ones
+    #[arg(long)]
+    verify_only: bool,
 
-/// Pack bytes into Field elements (implements the same logic as Noir's pack_bytes)
-/// Splits input into 31-byte chunks and converts each to a Field element
-fn pack_bytes(bytes: &[u8]) -> Vec<String> {
-    let n = bytes.len();
-    let num_chunks = n / 31 + 1; // Matches Noir's N / 31 + 1
-    
-    // Pad bytes to (num_chunks * 31) length - matches Noir's pad_end
-    let padded_len = num_chunks * 31;
-    let mut bytes_padded = bytes.to_vec();
-    bytes_padded.resize(padded_len, 0);
-    
-    let mut result = Vec::new();
-    
-    // Process each 31-byte chunk
-    for i in 0..num_chunks {
-        let start = i * 31;
-        let chunk = &bytes_padded[start..start + 31];
-        
-        // Convert chunk to field using little-endian (matches Noir's field_from_bytes)
-        let mut field_value = BigUint::from(0u32);
-        let mut offset = BigUint::from(1u32);
-        
-        for &byte in chunk {
-            field_value += BigUint::from(byte) * &offset;
-            offset *= 256u32;
-        }
-        
-        result.push(field_value.to_string());
-    }
-    
-    result
-}
+    /// Number of limbs each value is split into for snarkjs/rapidsnark
+    /// (default: 6, or enough to hold 256 bits at --chunk-bits)
+    #[arg(long)]
+    num_chunks: Option<usize>,
 
-/// Normalize s value according to BIP-0062
-fn normalize_s(s: &[u8]) -> Vec<u8> {
-    let n = BigUint::from_bytes_be(&[
-        0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xBC, 0xE6, 0xFA, 0xAD, 0xA7, 0x17, 0x9E, 0x84,
-        0xF3, 0xB9, 0xCA, 0xC2, 0xFC, 0x63, 0x25, 0x51
-    ]);
-    let half_order = &n >> 1;
-    
-    let s_big = BigUint::from_bytes_be(s);
-    if s_big > half_order {
-        let new_s = &n - &s_big;
-        let mut normalized_bytes = vec![0u8; 32];
-        let s_bytes = new_s.to_bytes_be();
-        normalized_bytes[32 - s_bytes.len()..].copy_from_slice(&s_bytes);
-        normalized_bytes
-    } else {
-        s.to_vec()
-    }
-}
+    /// Bits per limb for snarkjs/rapidsnark
+    /// (default: 43, or the fewest that hold 256 bits in --num-chunks)
+    #[arg(long)]
+    chunk_bits: Option<u32>,
 
-/// Convert BigUint to array of 6 chunks of 43 bits each
-fn bigint_to_chunks(x: BigUint) -> Vec<String> {
-    let modulus = 2u128.pow(43).to_biguint().unwrap();
-    let mut chunks = Vec::new();
-    let mut x_temp = x;
-    
-    for _ in 0..6 {
-        let chunk = (&x_temp % &modulus).to_string();
-        // No padding, just the raw number as a string
-        chunks.push(chunk);
-        x_temp = x_temp / &modulus;
-    }
-    
-    chunks
-}
+    /// Import real WebAuthn assertions from a JSON file instead of generating random
+    /// signatures (one object or an array of them, fields base64url-encoded)
+    #[arg(long, value_name = "FILE")]
+    import_webauthn: Option<PathBuf>,
 
-/// Convert BigUint to hex string (for gnark)
-fn bigint_to_hex(x: BigUint) -> String {
-    format!("0x{}", x.to_str_radix(16))
-}
+    /// Import one existing signature instead of generating random ones, as hex:
+    /// DER-encoded or 64-byte compact r || s (needs --public-key and --message or --message-hash)
+    #[arg(long, value_name = "HEX", conflicts_with = "import_webauthn", requires = "public_key", requires = "signed_message")]
+    import_signature: Option<String>,
 
-/// Convert bytes to BigUint
-fn bytes_to_bigint(bytes: &[u8]) -> BigUint {
-    BigUint::from_bytes_be(bytes)
-}
+    /// SEC1-encoded P-256 public key (compressed or uncompressed) for --import-signature, as hex
+    #[arg(long, value_name = "HEX", requires = "import_signature")]
+    public_key: Option<String>,
 
-/// Generate Noir test case in TOML format with both byte arrays and Field values
-fn generate_noir_toml(
-    hashed_message: &[u8],
-    pub_key_x: &[u8],
-    pub_key_y: &[u8],
-    signature: &[u8],
-) -> String {
-    // Generate Field values using pack_bytes (matches Noir's pack_bytes logic)
-    let hashed_message_fields = pack_bytes(hashed_message);
-    let pub_key_x_fields = pack_bytes(pub_key_x);
-    let pub_key_y_fields = pack_bytes(pub_key_y);
-    let signature_r_fields = pack_bytes(&signature[0..32]);
-    let signature_s_fields = pack_bytes(&signature[32..64]);
-    
-    // Helper function to format field array for TOML
-    let format_field_array = |fields: &Vec<String>| -> String {
-        if fields.len() == 1 {
-            format!("\"{}\"", fields[0])
-        } else {
-            let quoted_fields: Vec<String> = fields.iter().map(|f| format!("\"{}\"", f)).collect();
-            format!("[{}]", quoted_fields.join(", "))
-        }
-    };
-    
-    format!(
-        r#"# Field values (matching Noir's pack_bytes - 31-byte chunks)
-hashed_message = {}
-pub_key_x = {}
-pub_key_y = {}
-signature_r = {}
-signature_s = {}
-"#,
-        format_field_array(&hashed_message_fields),
-        format_field_array(&pub_key_x_fields),
-        format_field_array(&pub_key_y_fields),
-        format_field_array(&signature_r_fields),
-        format_field_array(&signature_s_fields),
-    )
+    /// Message to sign, or the message the imported signature was made over
+    /// (default: "Test message for signature")
+    #[arg(long, conflicts_with = "import_webauthn")]
+    message: Option<String>,
+
+    /// Pre-hashed 32-byte digest to sign or that the imported signature was made over,
+    /// as hex (implies --hash none)
+    #[arg(long, value_name = "HEX", conflicts_with = "import_webauthn")]
+    message_hash: Option<String>,
+
+    /// Hash function applied to the message: sha256, keccak256, or none
+    /// (default: sha256, or none with --message-hash)
+    #[arg(long, value_name = "HASH", conflicts_with = "import_webauthn")]
+    hash: Option<HashFunction>,
+
+    /// Independent signatures per test case file, for circuits that amortize verification
+    /// across a batch (each invalid batch holds one corrupted signature)
+    #[arg(long, value_name = "N", default_value_t = 1, conflicts_with_all = ["import_webauthn", "import_signature"])]
+    batch_size: usize,
 }
 
 /// Ensure a directory exists, creating it if necessary
@@ -171,130 +82,290 @@ fn delete_directory_if_exists(dir_path: &Path) {
     }
 }
 
+/// Re-validate every existing test case file against its expected outcome in
+/// the manifest (test cases without a manifest entry are expected to be valid).
+/// Returns the number of files that failed.
+fn verify_existing_test_cases() -> usize {
+    let mut failures = 0;
+    for stack in Stack::ALL {
+        let dir = stack.tests_dir();
+        // Only a missing manifest falls back to the defaults; a broken one would
+        // silently check every test case against the wrong expectations
+        let manifest_path = dir.join("manifest.json");
+        let manifest: Option<Manifest> = match fs::read_to_string(&manifest_path) {
+            Ok(contents) => match serde_json::from_str(&contents) {
+                Ok(manifest) => Some(manifest),
+                Err(e) => {
+                    println!("❌ {}: could not parse manifest: {}", manifest_path.display(), e);
+                    failures += 1;
+                    continue;
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => {
+                println!("❌ {}: could not read manifest: {}", manifest_path.display(), e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        let writer = stack.writer(manifest.as_ref().map(|m| m.chunk_layout).unwrap_or_default());
+        let batch_size = manifest.as_ref().map_or(1, |m| m.batch_size);
+
+        let mut paths: Vec<PathBuf> = match fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == writer.extension()))
+                .filter(|path| path.file_name().is_some_and(|name| name != "manifest.json"))
+                .collect(),
+            Err(_) => {
+                println!("⏭️  {} not found, skipping", dir.display());
+                continue;
+            }
+        };
+        paths.sort();
+
+        for path in paths {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let expected_valid = manifest
+                .as_ref()
+                .and_then(|m| m.test_cases.iter().find(|entry| entry.name == name))
+                .is_none_or(|entry| entry.expected == "valid");
+
+            // A batch is valid only if every signature in it verifies
+            match writer.read_batch(&path, batch_size) {
+                Ok(batch) if batch.iter().all(EcdsaTestCase::verify) == expected_valid => {
+                    println!("✅ {}", path.display());
+                }
+                Ok(_) => {
+                    println!(
+                        "❌ {}: expected {} but {}",
+                        path.display(),
+                        if expected_valid { "valid" } else { "invalid" },
+                        if expected_valid { "a signature does not verify" } else { "every signature verifies" },
+                    );
+                    failures += 1;
+                }
+                Err(e) => {
+                    println!("❌ {}: could not parse test case: {}", path.display(), e);
+                    failures += 1;
+                }
+            }
+        }
+    }
+    failures
+}
+
+/// Sign `message_hash` with `batch_size` fresh random keys
+fn sign_batch(batch_size: usize, message_hash: &[u8]) -> Vec<EcdsaTestCase> {
+    (0..batch_size).map(|_| EcdsaTestCase::sign_prehash(message_hash)).collect()
+}
+
+/// Resolve --hash against --message-hash, which is already a digest
+fn resolve_hash(args: &Args) -> Result<HashFunction, String> {
+    match (args.hash, &args.message_hash) {
+        (Some(HashFunction::None) | None, Some(_)) => Ok(HashFunction::None),
+        (Some(hash), Some(_)) => Err(format!("--message-hash is already a digest and cannot be hashed with {}", hash.name())),
+        (Some(HashFunction::None), None) => Err("--hash none needs the digest in --message-hash".to_string()),
+        (hash, None) => Ok(hash.unwrap_or_default()),
+    }
+}
+
+/// Build the test case for --import-signature from the hex arguments
+fn import_signature(args: &Args, signature: &str, message_hash: &[u8]) -> Result<EcdsaTestCase, String> {
+    let signature = import::decode_hex("signature", signature)?;
+    let public_key = import::decode_hex("public key", args.public_key.as_deref().unwrap_or_default())?;
+    import::test_case_from_signature(&signature, &public_key, message_hash)
+}
+
 fn main() {
     let args = Args::parse();
-    println!("Generating {} ECDSA test cases...", args.num_test_cases);
+
+    if args.verify_only {
+        println!("Verifying existing test cases...");
+        let failures = verify_existing_test_cases();
+        if failures > 0 {
+            eprintln!("{} file(s) failed verification", failures);
+            process::exit(1);
+        }
+        println!("All test case files match their expected outcome!");
+        return;
+    }
+
+    if args.batch_size == 0 {
+        eprintln!("--batch-size must be at least 1");
+        process::exit(1);
+    }
+
+    let layout = match ChunkLayout::resolve(args.num_chunks, args.chunk_bits) {
+        Ok(layout) => layout,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    // WebAuthn assertions are always signed over a SHA-256 digest
+    let hash = if args.import_webauthn.is_some() { Ok(HashFunction::Sha256) } else { resolve_hash(&args) };
+    let hash = hash.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
 
     // Create a simple message to hash (will be different for each test case)
-    let message = b"Test message for signature";
-
-    // Prepare output directories
-    let snarkjs_tests_dir = Path::new("snarkjs").join("tests");
-    let rapidsnark_tests_dir = Path::new("rapidsnark").join("tests");
-    let noir_tests_dir = Path::new("noir").join("tests");
-    let gnark_tests_dir = Path::new("gnark").join("tests");
-
-    // Clean existing directories
-    for dir in [&snarkjs_tests_dir, &rapidsnark_tests_dir, &noir_tests_dir, &gnark_tests_dir] {
-        delete_directory_if_exists(dir);
-        ensure_directory_exists(dir);
+    let message = args.message.as_deref().unwrap_or("Test message for signature");
+
+    // Hash the message, or take the caller's digest as is
+    let message_hash = match &args.message_hash {
+        Some(digest) => import::decode_hex("message hash", digest).and_then(|digest| hash.digest(&digest)),
+        None => hash.digest(message.as_bytes()),
+    };
+    let message_hash = message_hash.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let mut test_cases = Vec::new();
+    let mut manifest = Manifest { chunk_layout: layout, hash, batch_size: args.batch_size, test_cases: Vec::new() };
+
+    if let Some(path) = &args.import_webauthn {
+        println!("Importing WebAuthn assertions from {}...", path.display());
+        let assertions = fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| webauthn::parse_assertions(&contents))
+            .unwrap_or_else(|e| {
+                eprintln!("Could not read {}: {}", path.display(), e);
+                process::exit(1);
+            });
+        for (i, assertion) in assertions.iter().enumerate() {
+            let test_case = assertion.to_test_case().unwrap_or_else(|e| {
+                eprintln!("Assertion {} in {} is invalid: {}", i + 1, path.display(), e);
+                process::exit(1);
+            });
+            let name = format!("test_case_{}", i + 1);
+            test_cases.push((name.clone(), vec![test_case]));
+            manifest.test_cases.push(ManifestEntry {
+                name,
+                expected: "valid".to_string(),
+                corruption: None,
+                source: Some("webauthn".to_string()),
+            });
+        }
+    } else if let Some(signature) = &args.import_signature {
+        println!("Importing signature...");
+        let test_case = import_signature(&args, signature, &message_hash).unwrap_or_else(|e| {
+            eprintln!("Could not import signature: {}", e);
+            process::exit(1);
+        });
+        if !test_case.verify() {
+            eprintln!("Imported signature does not verify against the public key and message hash; no files were written");
+            process::exit(1);
+        }
+        test_cases.push(("test_case_1".to_string(), vec![test_case]));
+        manifest.test_cases.push(ManifestEntry {
+            name: "test_case_1".to_string(),
+            expected: "valid".to_string(),
+            corruption: None,
+            source: Some("signature".to_string()),
+        });
+    } else {
+        if args.batch_size > 1 {
+            println!(
+                "Generating {} batches of {} ECDSA signatures ({})...",
+                args.num_test_cases, args.batch_size, hash.name()
+            );
+        } else {
+            println!("Generating {} ECDSA test cases ({})...", args.num_test_cases, hash.name());
+        }
+
+        // Generate test cases, each a batch of independently keyed signatures
+        for i in 0..args.num_test_cases {
+            let name = format!("test_case_{}", i + 1);
+            test_cases.push((name.clone(), sign_batch(args.batch_size, &message_hash)));
+            manifest.test_cases.push(ManifestEntry { name, expected: "valid".to_string(), corruption: None, source: None });
+        }
     }
+    let num_valid = test_cases.len();
 
-    // Generate test cases
-    for i in 0..args.num_test_cases {
-        // Generate key pair
-        let signing_key = SigningKey::random(&mut OsRng);
-        let verifying_key = signing_key.verifying_key();
-        
-        // Hash the message with SHA256
-        let mut hasher = Sha256::new();
-        hasher.update(message);
-        let message_hash = hasher.finalize().to_vec();
-        
-        // Sign the original message (not the hash)
-        let signature: Signature = signing_key.sign(message);
-        
-        // Extract public key coordinates
-        let pubkey_bytes = verifying_key.to_encoded_point(false);
-        let pubkey_x = &pubkey_bytes.as_bytes()[1..33];
-        let pubkey_y = &pubkey_bytes.as_bytes()[33..65];
-        
-        // Extract signature components
-        let signature_bytes = signature.to_bytes();
-        let (r, s) = signature_bytes.split_at(32);
-        
-        // Normalize s value according to BIP-0062
-        let normalized_s = normalize_s(s);
-        
-        // Convert values to BigUint
-        let r_bigint = bytes_to_bigint(r);
-        let s_bigint = bytes_to_bigint(&normalized_s);
-        let msghash_bigint = bytes_to_bigint(&message_hash);
-        let pubkey_x_bigint = bytes_to_bigint(pubkey_x);
-        let pubkey_y_bigint = bytes_to_bigint(pubkey_y);
-        
-        // Convert BigUints to chunks for snarkjs/rapidsnark
-        let r_chunks = bigint_to_chunks(r_bigint.clone());
-        let s_chunks = bigint_to_chunks(s_bigint.clone());
-        let msghash_chunks = bigint_to_chunks(msghash_bigint.clone());
-        let pubkey_x_chunks = bigint_to_chunks(pubkey_x_bigint.clone());
-        let pubkey_y_chunks = bigint_to_chunks(pubkey_y_bigint.clone());
-        
-        // Create SnarkJS/Rapidsnark test case with chunked values
-        let snarkjs_test_case = SnarkjsTestCase {
-            r: r_chunks,
-            s: s_chunks,
-            msghash: msghash_chunks,
-            pubkey: vec![
-                pubkey_x_chunks,
-                pubkey_y_chunks,
-            ],
-        };
+    // Generate one invalid test case per kind of corruption, each from a fresh valid batch
+    // whose last signature is corrupted
+    if args.include_invalid {
+        for (i, corruption) in Corruption::ALL.iter().enumerate() {
+            let name = format!("invalid_case_{}", i + 1);
+            let mut batch = sign_batch(args.batch_size, &message_hash);
+            let last = batch.pop().expect("batch size is at least 1");
+            batch.push(last.corrupt(*corruption));
+            test_cases.push((name.clone(), batch));
+            manifest.test_cases.push(ManifestEntry {
+                name,
+                expected: "invalid".to_string(),
+                corruption: Some(corruption.name().to_string()),
+                source: None,
+            });
+        }
+    }
 
-        // Create gnark test case with hex strings
-        let gnark_test_case = GnarkTestCase {
-            r: bigint_to_hex(r_bigint),
-            s: bigint_to_hex(s_bigint),
-            msghash: bigint_to_hex(msghash_bigint),
-            pubkey_x: bigint_to_hex(pubkey_x_bigint),
-            pubkey_y: bigint_to_hex(pubkey_y_bigint),
-        };
+    // Cross-check every test case with a native verifier before writing anything
+    for ((name, batch), entry) in test_cases.iter().zip(&manifest.test_cases) {
+        let expected_valid = entry.expected == "valid";
+        if batch.iter().all(EcdsaTestCase::verify) != expected_valid {
+            eprintln!(
+                "Native verification of {} disagrees with its expected outcome ({}); no files were written",
+                name, entry.expected
+            );
+            process::exit(1);
+        }
+    }
+
+    let manifest_json = serde_json::to_string_pretty(&manifest)
+        .expect("Failed to serialize manifest");
 
-        // Save SnarkJS/Rapidsnark test cases
-        let snarkjs_json = serde_json::to_string_pretty(&snarkjs_test_case)
-            .expect("Failed to serialize snarkjs test case");
-        
-        for dir in &[&snarkjs_tests_dir, &rapidsnark_tests_dir] {
-            let file_path = dir.join(format!("test_case_{}.json", i + 1));
-            fs::write(&file_path, &snarkjs_json)
-                .expect("Failed to write test case file");
+    for stack in Stack::ALL {
+        // Clean the existing directory
+        let dir = stack.tests_dir();
+        delete_directory_if_exists(&dir);
+        ensure_directory_exists(&dir);
+
+        let writer = stack.writer(layout);
+        for (name, batch) in &test_cases {
+            writer.write_batch(&dir, name, batch)
+                .unwrap_or_else(|e| panic!("Failed to write {} test case {}: {}", stack.name(), name, e));
         }
-        
-        // Save gnark test case
-        let gnark_json = serde_json::to_string_pretty(&gnark_test_case)
-            .expect("Failed to serialize gnark test case");
-        let gnark_file_path = gnark_tests_dir.join(format!("test_case_{}.json", i + 1));
-        fs::write(&gnark_file_path, gnark_json)
-            .expect("Failed to write gnark test case");
-        
-        // Create and save Noir test case
-        let noir_test = generate_noir_toml(
-            &message_hash,
-            pubkey_x,
-            pubkey_y,
-            &[r, &normalized_s].concat(),
-        );
-        
-        let noir_file_path = noir_tests_dir.join(format!("test_case_{}.toml", i + 1));
-        fs::write(&noir_file_path, noir_test)
-            .expect("Failed to write Noir test case");
+
+        // Save the manifest next to the stack's test cases
+        fs::write(dir.join("manifest.json"), &manifest_json)
+            .expect("Failed to write manifest");
     }
 
     println!("Test cases generated successfully for SnarkJS, Rapidsnark, Noir, and gnark!");
-    println!("Files are saved with 6 chunks of 43 bits each for snarkjs/rapidsnark.");
+    println!("Files are saved with {} chunks of {} bits each for snarkjs/rapidsnark.", layout.num_chunks, layout.chunk_bits);
     println!("Hex strings are used for gnark (native big integer format).");
+    if args.batch_size > 1 {
+        println!("Each test case file holds a batch of {} signatures, one array entry per signature.", args.batch_size);
+    }
+    if args.include_invalid {
+        println!("Included {} invalid test cases (invalid_case_*) expected to be rejected.", Corruption::ALL.len());
+    }
 
     // Print sample case details for verification
-    if args.num_test_cases > 0 {
+    if num_valid > 0 {
         println!("\nSample test case (index 0):");
-        println!("Message: {}", String::from_utf8_lossy(message));
+        if args.import_webauthn.is_some() {
+            println!("Message: SHA-256(authenticatorData || SHA-256(clientDataJSON)) of each assertion");
+        } else if let Some(message_hash) = &args.message_hash {
+            println!("Message: pre-hashed ({})", message_hash);
+        } else {
+            println!("Message: {}", message);
+            println!("Hash: {}", hash.name());
+        }
         println!("Message Hash: see generated files");
         println!("Public Key X and Y: see generated files");
         println!("Signature R and S: see generated files");
         println!("\nTest files have been written to:");
-        println!("  - {} (6 chunks of 43 bits)", snarkjs_tests_dir.display());
-        println!("  - {} (6 chunks of 43 bits)", rapidsnark_tests_dir.display());
-        println!("  - {} (TOML format)", noir_tests_dir.display());
-        println!("  - {} (hex strings)", gnark_tests_dir.display());
+        println!("  - {} ({} chunks of {} bits)", Stack::Snarkjs.tests_dir().display(), layout.num_chunks, layout.chunk_bits);
+        println!("  - {} ({} chunks of {} bits)", Stack::Rapidsnark.tests_dir().display(), layout.num_chunks, layout.chunk_bits);
+        println!("  - {} (TOML format)", Stack::Noir.tests_dir().display());
+        println!("  - {} (hex strings)", Stack::Gnark.tests_dir().display());
+        println!("  - manifest.json in each directory lists every test case and whether it should verify");
     }
 }
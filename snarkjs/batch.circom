@@ -0,0 +1,30 @@
+pragma circom 2.1.5;
+
+include "./lib/circom-ecdsa-p256/circuits/ecdsa.circom";
+
+// Verifies b independent signatures in one proof. result is 1 only if every
+// signature verifies, so the proving cost is amortized over the whole batch.
+template BatchECDSAVerifyNoPubkeyCheck(n, k, b) {
+    signal input r[b][k];
+    signal input s[b][k];
+    signal input msghash[b][k];
+    signal input pubkey[b][2][k];
+
+    signal output result;
+
+    component verifiers[b];
+    signal all_valid[b + 1];
+    all_valid[0] <== 1;
+    for (var i = 0; i < b; i++) {
+        verifiers[i] = ECDSAVerifyNoPubkeyCheck(n, k);
+        for (var j = 0; j < k; j++) {
+            verifiers[i].r[j] <== r[i][j];
+            verifiers[i].s[j] <== s[i][j];
+            verifiers[i].msghash[j] <== msghash[i][j];
+            verifiers[i].pubkey[0][j] <== pubkey[i][0][j];
+            verifiers[i].pubkey[1][j] <== pubkey[i][1][j];
+        }
+        all_valid[i + 1] <== all_valid[i] * verifiers[i].result;
+    }
+    result <== all_valid[b];
+}
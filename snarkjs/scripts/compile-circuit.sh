@@ -16,7 +16,7 @@ fi
 echo "🔨 Starting circuit compilation..."
 
 # Compile the circuit
-echo "📝 Compiling circuit.circom..."
+echo "📝 Compiling the circuit..."
 
 # Create the compilation output directory
 mkdir -p /out/setup
@@ -28,6 +28,32 @@ if [ -d "/out/setup" ] && [ "$(ls -A /out/setup)" ]; then
     rm -rf /out/setup/*
 fi
 
-circom circuit.circom --r1cs --wasm -o /out/setup
+# circom-ecdsa-p256 only implements 6 chunks of 43 bits, which circuit.circom
+# and the batch circuit below are both built with
+CHUNK_BITS=$(jq '.chunk_layout.chunk_bits // 43' ./tests/manifest.json 2>/dev/null || echo 43)
+NUM_CHUNKS=$(jq '.chunk_layout.num_chunks // 6' ./tests/manifest.json 2>/dev/null || echo 6)
+if [ "$CHUNK_BITS" -ne 43 ] || [ "$NUM_CHUNKS" -ne 6 ]; then
+    echo "❌ Error: the test cases use $NUM_CHUNKS chunks of $CHUNK_BITS bits, but the circuit only supports 6 chunks of 43 bits"
+    echo "   Regenerate them without --num-chunks or --chunk-bits"
+    exit 1
+fi
+
+# Test cases generated with --batch-size hold several signatures per file and
+# need a circuit that verifies the whole batch in one proof
+BATCH_SIZE=$(jq '.batch_size // 1' ./tests/manifest.json 2>/dev/null || echo 1)
+CIRCUIT=circuit.circom
+if [ "$BATCH_SIZE" -gt 1 ]; then
+    CIRCUIT="$(mktemp -d)/circuit.circom"
+    cat > "$CIRCUIT" << EOF
+pragma circom 2.1.5;
+
+include "batch.circom";
+
+component main { public [msghash] } = BatchECDSAVerifyNoPubkeyCheck($CHUNK_BITS, $NUM_CHUNKS, $BATCH_SIZE);
+EOF
+    echo "📦 Batching $BATCH_SIZE signatures per proof ($NUM_CHUNKS chunks of $CHUNK_BITS bits)"
+fi
+
+circom "$CIRCUIT" --r1cs --wasm -l "$(pwd)" -o /out/setup
 
 echo "✅ Circuit compilation completed successfully!" 
\ No newline at end of file
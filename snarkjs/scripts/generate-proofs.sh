@@ -5,6 +5,10 @@ set -e
 
 echo "🔐 [4/5] Generating proofs..."
 
+# Timed runs and untimed warmup runs per test case (set by the benchmark runner)
+BENCH_RUNS=${BENCH_RUNS:-1}
+BENCH_WARMUP=${BENCH_WARMUP:-0}
+
 # Create directories for proofs and benchmark results
 mkdir -p /out/proofs
 
@@ -88,12 +92,9 @@ echo "📊 Detected ${TOTAL_MEM_MB}MB RAM, allocating ${NODE_MEMORY}MB to Node.j
 echo "🔄 Generating proofs..."
 TEST_CASES_LIST=$(printf "%s," "${TEST_CASE_NUMBERS[@]}" | sed 's/,$//')
 
-hyperfine --min-runs 1 --max-runs 1 \
-    -L test_case $TEST_CASES_LIST \
-    --show-output \
-    --export-json /out/benchmarks/all_proofs_benchmark.json \
-    --export-markdown /out/benchmarks/proofs_summary.md \
-    "NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY snarkjs groth16 prove /out/setup/circuit.zkey /out/witnesses/witness_{test_case}.wtns /out/proofs/proof_{test_case}.json /out/proofs/public_{test_case}.json"
+/app/scripts/time-test-cases.sh proofs "$BENCH_RUNS" "$TEST_CASES_LIST" \
+    "NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY snarkjs groth16 prove /out/setup/circuit.zkey /out/witnesses/witness_{test_case}.wtns /out/proofs/proof_{test_case}.json /out/proofs/public_{test_case}.json" \
+    --show-output
 
 echo "✅ All proofs generated successfully!"
 
@@ -101,6 +102,7 @@ echo "✅ All proofs generated successfully!"
 echo ""
 echo "📈 Aggregate Statistics:"
 echo "----------------------------------------"
+echo "Runs per test case: $BENCH_RUNS (warmup: $BENCH_WARMUP)"
 
 # Check if the JSON file exists and is valid
 if [ ! -f "/out/benchmarks/all_proofs_benchmark.json" ]; then
@@ -109,25 +111,29 @@ if [ ! -f "/out/benchmarks/all_proofs_benchmark.json" ]; then
 fi
 
 # Calculate statistics with error handling
-if ! avg_time=$(jq -r '([.results[].mean | select(. != null)] | add) / ([.results[].mean | select(. != null)] | length)' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
+if ! avg_time=$(jq -r '[.results[].times[]] | add / length' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
     echo "Error: Could not calculate average time"
     exit 1
 fi
 
-if ! min_time=$(jq -r '[.results[].min | select(. != null)] | min' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
+if ! min_time=$(jq -r '[.results[].times[]] | min' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
     echo "Error: Could not calculate minimum time"
     exit 1
 fi
 
-if ! max_time=$(jq -r '[.results[].max | select(. != null)] | max' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
+if ! max_time=$(jq -r '[.results[].times[]] | max' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
     echo "Error: Could not calculate maximum time"
     exit 1
 fi
 
+if ! median_time=$(jq -r -L /app/scripts 'include "stats"; [.results[].times[]] | median' /out/benchmarks/all_proofs_benchmark.json 2>/dev/null); then
+    echo "Error: Could not calculate median time"
+    exit 1
+fi
+
 # Calculate standard deviation
 if ! std_dev=$(jq -r '
-    .results | 
-    map(.mean) | 
+    [.results[].times[]] |
     (add / length) as $mean |
     map(($mean - .) * ($mean - .)) |
     (add / length) | 
@@ -138,8 +144,9 @@ if ! std_dev=$(jq -r '
 fi
 
 # Only print if we have valid numbers
-if [[ "$avg_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$min_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$max_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$std_dev" =~ ^[0-9]+\.?[0-9]*$ ]]; then
+if [[ "$avg_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$median_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$min_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$max_time" =~ ^[0-9]+\.?[0-9]*$ ]] && [[ "$std_dev" =~ ^[0-9]+\.?[0-9]*$ ]]; then
     printf "Average Time: %.3f ± %.3f seconds\n" $avg_time $std_dev
+    printf "Median Time: %.3f seconds\n" $median_time
     printf "Min Time: %.3f seconds\n" $min_time
     printf "Max Time: %.3f seconds\n" $max_time
 else
@@ -0,0 +1,119 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+# Deploys the Groth16 Solidity verifier built by benchmark-gas.sh to a local
+# anvil chain and submits every proof to it in a transaction. Unlike the forge
+# gas report, the gas recorded here is what a user pays: the whole transaction,
+# including the 21000 base cost and the calldata.
+
+echo "⛓️  Measuring on-chain verification..."
+
+FOUNDRY_PROJECT=/out/gas-reports/foundry
+if [ ! -f "$FOUNDRY_PROJECT/src/Groth16Verifier.sol" ]; then
+    echo "❌ Solidity verifier not found, run benchmark-gas.sh first"
+    exit 1
+fi
+
+mkdir -p /out/benchmarks
+
+# anvil's first prefunded development account
+RPC_URL=http://127.0.0.1:8545
+PRIVATE_KEY=0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80
+# Contracts larger than EIP-170's limit cannot be deployed on mainnet, but are
+# still deployed here so their verification gas can be measured
+CODE_SIZE_LIMIT=24576
+
+anvil --silent --disable-code-size-limit &
+ANVIL_PID=$!
+trap 'kill $ANVIL_PID 2>/dev/null || true' EXIT
+for _ in $(seq 50); do
+    cast block-number --rpc-url "$RPC_URL" > /dev/null 2>&1 && break
+    sleep 0.2
+done
+
+# Receipts report gas as hex in newer foundry versions
+to_dec() {
+    case "$1" in
+        0x*) cast to-dec "$1" ;;
+        *) echo "$1" ;;
+    esac
+}
+
+cd "$FOUNDRY_PROJECT"
+# Older foundry versions broadcast by default and have no --broadcast flag
+BROADCAST=""
+if forge create --help | grep -q -- '--broadcast'; then
+    BROADCAST=--broadcast
+fi
+echo "🚀 Deploying Groth16Verifier..."
+DEPLOYMENT=$(forge create src/Groth16Verifier.sol:Groth16Verifier \
+    --rpc-url "$RPC_URL" --private-key "$PRIVATE_KEY" $BROADCAST --json)
+VERIFIER=$(echo "$DEPLOYMENT" | jq -r '.deployedTo')
+DEPLOYMENT_GAS=$(to_dec "$(cast receipt --json "$(echo "$DEPLOYMENT" | jq -r '.transactionHash')" --rpc-url "$RPC_URL" | jq -r '.gasUsed')")
+BYTECODE_BYTES=$(cast codesize "$VERIFIER" --rpc-url "$RPC_URL")
+echo "   Deployed at $VERIFIER ($BYTECODE_BYTES bytes, $DEPLOYMENT_GAS gas)"
+
+PUBLIC_INPUTS_COUNT=$(jq -r '.nPublic' /out/setup/verification_key.json)
+SIGNATURE="verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[$PUBLIC_INPUTS_COUNT])"
+
+# Discover test cases from tests directory
+TEST_CASE_FILES=(/app/tests/test_case_*.json)
+if [ ! -e "${TEST_CASE_FILES[0]}" ]; then
+    echo "❌ No test case files found in tests directory!"
+    exit 1
+fi
+
+# Extract test case numbers and sort them
+TEST_CASE_NUMBERS=()
+for file in "${TEST_CASE_FILES[@]}"; do
+    if [[ $file =~ test_case_([0-9]+)\.json ]]; then
+        TEST_CASE_NUMBERS+=(${BASH_REMATCH[1]})
+    fi
+done
+IFS=$'\n' TEST_CASE_NUMBERS=($(sort -n <<<"${TEST_CASE_NUMBERS[*]}"))
+unset IFS
+
+RESULTS=()
+for i in "${TEST_CASE_NUMBERS[@]}"; do
+    # generatecall prints the four arguments as JSON arrays; cast wants them unquoted
+    ARGS="[$(snarkjs generatecall /out/proofs/public_${i}.json /out/proofs/proof_${i}.json)]"
+    CALLDATA=$(cast calldata "$SIGNATURE" \
+        "$(echo "$ARGS" | jq -r '.[0] | "[" + join(",") + "]"')" \
+        "$(echo "$ARGS" | jq -r '.[1] | map("[" + join(",") + "]") | "[" + join(",") + "]"')" \
+        "$(echo "$ARGS" | jq -r '.[2] | "[" + join(",") + "]"')" \
+        "$(echo "$ARGS" | jq -r '.[3] | "[" + join(",") + "]"')")
+    CALLDATA_BYTES=$(( (${#CALLDATA} - 2) / 2 ))
+
+    # The verifier returns false instead of reverting on an invalid proof
+    if [ "$(cast call "$VERIFIER" "$CALLDATA" --rpc-url "$RPC_URL")" != "0x$(printf '%064x' 1)" ]; then
+        echo "❌ The on-chain verifier rejected the proof for test case $i"
+        exit 1
+    fi
+
+    RECEIPT=$(cast send "$VERIFIER" "$CALLDATA" --rpc-url "$RPC_URL" --private-key "$PRIVATE_KEY" --json)
+    GAS_USED=$(to_dec "$(echo "$RECEIPT" | jq -r '.gasUsed')")
+    echo "   Test case $i: $GAS_USED gas, $CALLDATA_BYTES bytes of calldata"
+    RESULTS+=("{\"test_case\": $i, \"gas_used\": $GAS_USED, \"calldata_bytes\": $CALLDATA_BYTES}")
+done
+
+jq -n \
+    --arg verifier Groth16Verifier \
+    --argjson deployment_gas "$DEPLOYMENT_GAS" \
+    --argjson bytecode_bytes "$BYTECODE_BYTES" \
+    --argjson code_size_limit "$CODE_SIZE_LIMIT" \
+    --argjson results "[$(IFS=,; echo "${RESULTS[*]}")]" \
+    '{
+        verifier: $verifier,
+        deployment_gas: $deployment_gas,
+        bytecode_bytes: $bytecode_bytes,
+        exceeds_code_size_limit: ($bytecode_bytes > $code_size_limit),
+        results: $results
+    }' > /out/benchmarks/onchain_verification.json
+
+cd /app
+
+echo "✅ On-chain verification results saved to /out/benchmarks/onchain_verification.json"
+jq -r '"   Average: \([.results[].gas_used] | add / length | round) gas, \([.results[].calldata_bytes] | add / length | round) bytes of calldata"' \
+    /out/benchmarks/onchain_verification.json
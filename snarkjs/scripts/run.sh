@@ -5,17 +5,58 @@ set -e
 
 
 echo "🚀 Starting ECDSA SNARK benchmark setup..."
-# Compile circuit
-echo "🔨 Compiling circuit..."
-./scripts/compile-circuit.sh
 
-# Run trusted setup
-echo "🔑 Running trusted setup..."
-./scripts/trusted-setup.sh
+# Same hash as bench.py's circuit_fingerprint: the circom sources, then the
+# batch size and chunk layout from the test manifest as compact sorted JSON
+circuit_fingerprint() {
+    {
+        for source in circuit.circom batch.circom; do
+            if [ -f "/app/$source" ]; then cat "/app/$source"; fi
+        done
+        jq -jcS '{batch_size: (.batch_size // 1), chunk_layout}' /app/tests/manifest.json 2>/dev/null \
+            || printf '{"batch_size":1,"chunk_layout":null}'
+    } | sha256sum | cut -d' ' -f1
+}
 
-# Compute witnesses
-echo "🧮 Computing witnesses..."
-./scripts/compute-witnesses.sh
+# Reuse the circuit and trusted setup cached by `bench.py setup` when mounted at
+# /artifacts, as long as CIRCUIT_FINGERPRINT says they were built for this circuit
+# and test case layout
+if [ -f "/artifacts/setup/circuit.zkey" ] && [ "${CIRCUIT_FINGERPRINT:-}" = "$(circuit_fingerprint)" ]; then
+    # Proofs kept by a resumed run only verify with the setup they were made with
+    if [ "${RESUME:-0}" = "1" ] && ! cmp -s /artifacts/setup/circuit.zkey /out/setup/circuit.zkey; then
+        echo "⚠️  The cached setup changed since this run was interrupted, timing every test case again..."
+        export RESUME=0
+    fi
+    echo "♻️  Using the cached circuit and trusted setup..."
+    mkdir -p /out/setup
+    cp -r /artifacts/setup/. /out/setup/
+elif [ "${RESUME:-0}" = "1" ] && [ -f "/out/setup/circuit.zkey" ]; then
+    echo "♻️  Resuming with the circuit and trusted setup already in /out..."
+else
+    if [ -f "/artifacts/setup/circuit.zkey" ]; then
+        echo "⚠️  The cached setup was built for a different circuit or test case layout, rebuilding it..."
+    fi
+    # Compile circuit
+    echo "🔨 Compiling circuit..."
+    ./scripts/compile-circuit.sh
+
+    # Run trusted setup
+    echo "🔑 Running trusted setup..."
+    ./scripts/trusted-setup.sh
+fi
+
+# Reuse witnesses pre-generated by `bench.py witnesses` when mounted at /witnesses,
+# so that only proving is timed here
+if [ -f "/witnesses/benchmarks/all_witnesses_benchmark.json" ]; then
+    echo "♻️  Using cached witnesses..."
+    mkdir -p /out/witnesses /out/benchmarks
+    cp /witnesses/witnesses/*.wtns /out/witnesses/
+    cp /witnesses/benchmarks/all_witnesses_benchmark.json /witnesses/benchmarks/witnesses_summary.md /out/benchmarks/
+else
+    # Compute witnesses
+    echo "🧮 Computing witnesses..."
+    ./scripts/compute-witnesses.sh
+fi
 
 # Generate proofs
 echo "🔐 Generating proofs..."
@@ -25,8 +66,37 @@ echo "🔐 Generating proofs..."
 echo "🔍 Verifying proofs..."
 ./scripts/verify-proofs.sh
 
+# Measure artifact sizes
+echo "📏 Measuring artifact sizes..."
+./scripts/measure-artifact-sizes.sh
+
 # Benchmark gas usage
 echo "⛽ Benchmarking gas usage..."
 ./scripts/benchmark-gas.sh
 
-echo "✅ All done! Check the benchmarks and gas-reports directories for results." 
\ No newline at end of file
+# Deploy the verifier to a local chain and record gas and calldata per verification
+echo "⛓️  Measuring on-chain verification..."
+./scripts/onchain-verification.sh
+
+# Check that invalid test cases are rejected
+echo "🛡️  Checking soundness..."
+# Accepting an invalid test case fails the run, after the remaining steps
+SOUNDNESS_FAILED=0
+./scripts/check-soundness.sh || SOUNDNESS_FAILED=1
+
+# Optionally measure proving time across thread counts
+if [ "$THREAD_SCALING" = "1" ]; then
+    echo "🧵 Measuring thread scaling..."
+    ./scripts/thread-scaling.sh
+fi
+
+# Record hardware and toolchain versions alongside the results
+echo "🖥️  Collecting environment metadata..."
+./scripts/collect-metadata.sh
+
+if [ "$SOUNDNESS_FAILED" = "1" ]; then
+    echo "❌ The circuit accepted invalid test cases, see /out/benchmarks/soundness.json"
+    exit 1
+fi
+
+echo "✅ All done! Check the benchmarks and gas-reports directories for results." 
@@ -0,0 +1,72 @@
+#!/bin/bash
+
+# Exit on error
+set -e
+
+echo "🧵 Measuring proving time across thread counts..."
+
+# Timed runs per thread count (set by the benchmark runner)
+BENCH_RUNS=${BENCH_RUNS:-1}
+
+# Check if trusted setup was run
+if [ ! -f "/out/setup/circuit.zkey" ]; then
+    echo "❌ Proving key not found!"
+    echo "   Please run trusted-setup.sh first."
+    exit 1
+fi
+
+# Proving cost doesn't depend on the signature being proved, so a single test
+# case is enough to measure scaling
+WITNESS_FILES=(/out/witnesses/witness_*.wtns)
+if [ ! -e "${WITNESS_FILES[0]}" ]; then
+    echo "❌ No witnesses found!"
+    echo "   Please run compute-witnesses.sh first."
+    exit 1
+fi
+WITNESS_FILE=$(printf "%s\n" "${WITNESS_FILES[@]}" | sort -V | head -n1)
+
+# Thread counts to test: 1, 2, 4, 8 and all cores, capped at the core count
+CPU_CORES=$(nproc)
+THREAD_COUNTS=()
+for threads in 1 2 4 8 $CPU_CORES; do
+    if [ "$threads" -le "$CPU_CORES" ] && [[ ! " ${THREAD_COUNTS[*]} " =~ " $threads " ]]; then
+        THREAD_COUNTS+=($threads)
+    fi
+done
+THREAD_COUNTS_LIST=$(printf "%s," "${THREAD_COUNTS[@]}" | sed 's/,$//')
+
+echo "🔍 Using $(basename "$WITNESS_FILE") with thread counts: ${THREAD_COUNTS[*]} ($BENCH_RUNS runs each)"
+
+# snarkjs sizes its worker pool from the visible CPUs, so the thread cap is
+# applied by pinning the prover to the first N cores
+NODE_MEMORY=${NODE_MEMORY_MB:-6144}
+mkdir -p /tmp/thread_scaling /out/benchmarks
+
+hyperfine --runs "$BENCH_RUNS" \
+    -L threads $THREAD_COUNTS_LIST \
+    --export-json /out/benchmarks/thread_scaling_benchmark.json \
+    --export-markdown /out/benchmarks/thread_scaling_summary.md \
+    "NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY taskset -c 0-\$(({threads} - 1)) snarkjs groth16 prove /out/setup/circuit.zkey $WITNESS_FILE /tmp/thread_scaling/proof.json /tmp/thread_scaling/public.json"
+
+# Speedup and parallel efficiency relative to the single-threaded run
+jq --argjson cores "$CPU_CORES" '{
+    cores: $cores,
+    results: (.results | (map(select(.parameters.threads == "1"))[0].mean) as $base | map({
+        threads: (.parameters.threads | tonumber),
+        mean,
+        median,
+        stddev,
+        min,
+        max,
+        speedup: ($base / .mean),
+        efficiency: ($base / .mean / (.parameters.threads | tonumber))
+    }))
+}' /out/benchmarks/thread_scaling_benchmark.json > /out/benchmarks/thread_scaling.json
+
+echo "✅ Thread scaling results saved to /out/benchmarks/thread_scaling.json"
+
+echo ""
+echo "📈 Thread Scaling:"
+echo "----------------------------------------"
+jq -r '.results[] | "\(.threads) threads: \(.mean * 1000 | round / 1000)s (speedup \(.speedup * 100 | round / 100)x)"' /out/benchmarks/thread_scaling.json
+echo "----------------------------------------"
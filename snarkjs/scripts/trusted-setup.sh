@@ -9,7 +9,10 @@ echo "🔐 Starting trusted setup..."
 mkdir -p /out/setup
 
 # Check if powers of tau file is mounted/available
-if [ ! -f "pot22_final.ptau" ]; then
+# Powers of tau to use; `bench.py setup` passes the smallest file that fits the circuit
+PTAU=${PTAU:-pot22_final.ptau}
+
+if [ ! -f "$PTAU" ]; then
     echo "❌ Powers of tau file not found!"
     echo "📝 Please mount the powers of tau file as a volume:"
     echo "   docker run -v /path/to/your/pot22_final.ptau:/app/pot22_final.ptau ..."
@@ -21,7 +24,7 @@ if [ ! -f "pot22_final.ptau" ]; then
     exit 1
 fi
 
-echo "✅ Powers of tau file found, proceeding with setup."
+echo "✅ Powers of tau file $PTAU found, proceeding with setup."
 
 # Detect available memory and set appropriate Node.js heap size (cross-platform)
 if [ -n "$NODE_MEMORY_MB" ]; then
@@ -75,7 +78,7 @@ fi
 echo "📊 Detected ${TOTAL_MEM_MB}MB RAM, allocating ${NODE_MEMORY}MB to Node.js"
 
 echo "📝 Generating proving key and verification key..."
-NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY snarkjs zkey new /out/setup/circuit.r1cs pot22_final.ptau /out/setup/circuit.zkey
+NODE_OPTIONS=--max_old_space_size=$NODE_MEMORY snarkjs zkey new /out/setup/circuit.r1cs "$PTAU" /out/setup/circuit.zkey
 
 echo "✅ Proving key and verification key generated successfully!"
 
@@ -0,0 +1,323 @@
+//! Conversions between 32-byte values and the integer encodings each stack expects
+
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+/// Limb layout of the chunked snarkjs/rapidsnark values. Common circom ECDSA
+/// parameterizations are 6×43 (the default), 4×64, and 3×86.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkLayout {
+    pub num_chunks: usize,
+    pub chunk_bits: u32,
+}
+
+impl Default for ChunkLayout {
+    fn default() -> Self {
+        ChunkLayout { num_chunks: 6, chunk_bits: 43 }
+    }
+}
+
+impl ChunkLayout {
+    /// Resolve a layout from optional chunk count and width, deriving whichever one is missing
+    pub fn resolve(num_chunks: Option<usize>, chunk_bits: Option<u32>) -> Result<Self, String> {
+        let layout = match (num_chunks, chunk_bits) {
+            (None, None) => ChunkLayout::default(),
+            (Some(num_chunks), Some(chunk_bits)) => ChunkLayout { num_chunks, chunk_bits },
+            (None, Some(chunk_bits)) if chunk_bits > 0 => ChunkLayout {
+                num_chunks: 256usize.div_ceil(chunk_bits as usize),
+                chunk_bits,
+            },
+            (Some(num_chunks), None) if num_chunks > 0 => ChunkLayout {
+                num_chunks,
+                chunk_bits: 256u32.div_ceil(num_chunks as u32),
+            },
+            _ => return Err("--num-chunks and --chunk-bits must be positive".to_string()),
+        };
+        if layout.num_chunks == 0 || layout.chunk_bits == 0 {
+            return Err("--num-chunks and --chunk-bits must be positive".to_string());
+        }
+        if layout.num_chunks * (layout.chunk_bits as usize) < 256 {
+            return Err(format!(
+                "{} chunks of {} bits cannot hold a 256-bit value",
+                layout.num_chunks, layout.chunk_bits
+            ));
+        }
+        Ok(layout)
+    }
+}
+
+/// Pack bytes into Field elements (implements the same logic as Noir's pack_bytes)
+/// Splits input into 31-byte chunks and converts each to a Field element
+pub fn pack_bytes(bytes: &[u8]) -> Vec<String> {
+    let n = bytes.len();
+    let num_chunks = n / 31 + 1; // Matches Noir's N / 31 + 1
+
+    // Pad bytes to (num_chunks * 31) length - matches Noir's pad_end
+    let padded_len = num_chunks * 31;
+    let mut bytes_padded = bytes.to_vec();
+    bytes_padded.resize(padded_len, 0);
+
+    let mut result = Vec::new();
+
+    // Process each 31-byte chunk
+    for i in 0..num_chunks {
+        let start = i * 31;
+        let chunk = &bytes_padded[start..start + 31];
+
+        // Convert chunk to field using little-endian (matches Noir's field_from_bytes)
+        let mut field_value = BigUint::from(0u32);
+        let mut offset = BigUint::from(1u32);
+
+        for &byte in chunk {
+            field_value += BigUint::from(byte) * &offset;
+            offset *= 256u32;
+        }
+
+        result.push(field_value.to_string());
+    }
+
+    result
+}
+
+/// Unpack Field elements produced by pack_bytes back into the original `len` bytes,
+/// rejecting any other number of fields or nonzero padding past `len`
+pub fn unpack_fields(fields: &[String], len: usize) -> Result<Vec<u8>, String> {
+    let expected_fields = len / 31 + 1;
+    if fields.len() != expected_fields {
+        return Err(format!("expected {} fields for {} bytes, found {}", expected_fields, len, fields.len()));
+    }
+    let mut bytes = Vec::new();
+    for field in fields {
+        let value: BigUint = field.parse().map_err(|_| format!("invalid field '{}'", field))?;
+        let mut chunk = value.to_bytes_le();
+        if chunk.len() > 31 {
+            return Err(format!("field '{}' does not fit in 31 bytes", field));
+        }
+        chunk.resize(31, 0);
+        bytes.extend_from_slice(&chunk);
+    }
+    if bytes[len..].iter().any(|&byte| byte != 0) {
+        return Err(format!("value does not fit in {} bytes", len));
+    }
+    bytes.truncate(len);
+    Ok(bytes)
+}
+
+/// Order of the P-256 group
+pub fn curve_order() -> BigUint {
+    BigUint::from_bytes_be(&[
+        0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xBC, 0xE6, 0xFA, 0xAD, 0xA7, 0x17, 0x9E, 0x84,
+        0xF3, 0xB9, 0xCA, 0xC2, 0xFC, 0x63, 0x25, 0x51
+    ])
+}
+
+/// Convert BigUint to a 32-byte big-endian array
+pub fn bigint_to_32_bytes(x: &BigUint) -> Vec<u8> {
+    let mut bytes = vec![0u8; 32];
+    let x_bytes = x.to_bytes_be();
+    bytes[32 - x_bytes.len()..].copy_from_slice(&x_bytes);
+    bytes
+}
+
+/// Convert a decoded value to 32 bytes, rejecting anything wider than 256 bits
+pub fn checked_32_bytes(x: &BigUint) -> Result<Vec<u8>, String> {
+    if x.bits() > 256 {
+        return Err(format!("value {} does not fit in 32 bytes", x));
+    }
+    Ok(bigint_to_32_bytes(x))
+}
+
+/// Normalize s value according to BIP-0062
+pub fn normalize_s(s: &[u8]) -> Vec<u8> {
+    let n = curve_order();
+    let half_order = &n >> 1;
+
+    let s_big = BigUint::from_bytes_be(s);
+    if s_big > half_order {
+        bigint_to_32_bytes(&(&n - &s_big))
+    } else {
+        s.to_vec()
+    }
+}
+
+/// Add one to a scalar modulo the group order, keeping it a well-formed but wrong value
+pub fn increment_scalar(x: &[u8]) -> Vec<u8> {
+    let n = curve_order();
+    bigint_to_32_bytes(&((BigUint::from_bytes_be(x) + 1u32) % &n))
+}
+
+/// Convert BigUint to an array of chunks (least significant first) in the given layout
+pub fn bigint_to_chunks(x: BigUint, layout: ChunkLayout) -> Vec<String> {
+    let modulus = BigUint::from(1u32) << layout.chunk_bits;
+    let mut chunks = Vec::new();
+    let mut x_temp = x;
+
+    for _ in 0..layout.num_chunks {
+        let chunk = (&x_temp % &modulus).to_string();
+        // No padding, just the raw number as a string
+        chunks.push(chunk);
+        x_temp /= &modulus;
+    }
+
+    chunks
+}
+
+/// Convert chunks (least significant first) in the given layout back to a BigUint
+pub fn chunks_to_bigint(chunks: &[String], layout: ChunkLayout) -> Result<BigUint, String> {
+    if chunks.len() != layout.num_chunks {
+        return Err(format!("expected {} chunks, found {}", layout.num_chunks, chunks.len()));
+    }
+    let mut x = BigUint::from(0u32);
+    for chunk in chunks.iter().rev() {
+        let value: BigUint = chunk.parse().map_err(|_| format!("invalid chunk '{}'", chunk))?;
+        if value.bits() > layout.chunk_bits as u64 {
+            return Err(format!("chunk '{}' does not fit in {} bits", chunk, layout.chunk_bits));
+        }
+        x = (x << layout.chunk_bits) + value;
+    }
+    Ok(x)
+}
+
+/// Convert BigUint to hex string (for gnark)
+pub fn bigint_to_hex(x: BigUint) -> String {
+    format!("0x{}", x.to_str_radix(16))
+}
+
+/// Convert a 0x-prefixed hex string (for gnark) to 32 bytes
+pub fn hex_to_32_bytes(hex_str: &str) -> Result<Vec<u8>, String> {
+    let digits = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    let x = BigUint::parse_bytes(digits.as_bytes(), 16)
+        .ok_or_else(|| format!("invalid hex value '{}'", hex_str))?;
+    checked_32_bytes(&x)
+}
+
+/// Convert bytes to BigUint
+pub fn bytes_to_bigint(bytes: &[u8]) -> BigUint {
+    BigUint::from_bytes_be(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequential_bytes() -> Vec<u8> {
+        (1..=32).collect()
+    }
+
+    #[test]
+    fn pack_bytes_splits_into_little_endian_31_byte_fields() {
+        let bytes = sequential_bytes();
+        let fields = pack_bytes(&bytes);
+
+        // 32 bytes need a second field for the last byte
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0], BigUint::from_bytes_le(&bytes[..31]).to_string());
+        assert_eq!(fields[1], "32");
+    }
+
+    #[test]
+    fn pack_bytes_always_emits_a_trailing_field() {
+        // Matches Noir's N / 31 + 1, even when N is a multiple of 31
+        assert_eq!(pack_bytes(&[]), vec!["0"]);
+        assert_eq!(pack_bytes(&[0xff; 31]).len(), 2);
+        assert_eq!(pack_bytes(&[0xff; 31])[1], "0");
+    }
+
+    #[test]
+    fn unpack_fields_inverts_pack_bytes() {
+        let bytes = sequential_bytes();
+        assert_eq!(unpack_fields(&pack_bytes(&bytes), 32).unwrap(), bytes);
+        assert_eq!(unpack_fields(&pack_bytes(&[0u8; 32]), 32).unwrap(), vec![0u8; 32]);
+    }
+
+    #[test]
+    fn unpack_fields_rejects_fields_wider_than_31_bytes() {
+        let oversized = (BigUint::from(1u32) << 248u32).to_string();
+        assert!(unpack_fields(&[oversized, "0".to_string()], 32).is_err());
+    }
+
+    #[test]
+    fn unpack_fields_rejects_too_few_or_too_many_fields() {
+        let fields = pack_bytes(&sequential_bytes());
+        assert!(unpack_fields(&fields[..1], 32).is_err());
+        assert!(unpack_fields(&[fields.clone(), vec!["0".to_string()]].concat(), 32).is_err());
+    }
+
+    #[test]
+    fn unpack_fields_rejects_nonzero_bytes_past_len() {
+        // 32 bytes end one byte into the second field
+        assert!(unpack_fields(&["0".to_string(), "256".to_string()], 32).is_err());
+    }
+
+    #[test]
+    fn normalize_s_flips_high_s() {
+        let n = curve_order();
+        let half_order = &n >> 1;
+
+        let high_s = bigint_to_32_bytes(&(&n - 5u32));
+        assert_eq!(normalize_s(&high_s), bigint_to_32_bytes(&BigUint::from(5u32)));
+
+        let boundary = bigint_to_32_bytes(&(&half_order + 1u32));
+        assert_eq!(bytes_to_bigint(&normalize_s(&boundary)), half_order);
+    }
+
+    #[test]
+    fn normalize_s_keeps_low_s() {
+        let half_order = bigint_to_32_bytes(&(curve_order() >> 1));
+        assert_eq!(normalize_s(&half_order), half_order);
+
+        let small = bigint_to_32_bytes(&BigUint::from(7u32));
+        assert_eq!(normalize_s(&small), small);
+    }
+
+    #[test]
+    fn bigint_to_chunks_is_least_significant_first() {
+        let x = (BigUint::from(3u32) << 43) + 2u32;
+        assert_eq!(bigint_to_chunks(x, ChunkLayout::default()), vec!["2", "3", "0", "0", "0", "0"]);
+
+        let x = (BigUint::from(1u32) << 64) + 9u32;
+        let layout = ChunkLayout { num_chunks: 4, chunk_bits: 64 };
+        assert_eq!(bigint_to_chunks(x, layout), vec!["9", "1", "0", "0"]);
+    }
+
+    #[test]
+    fn bigint_to_chunks_round_trips_in_common_layouts() {
+        let x = bytes_to_bigint(&[0xff; 32]);
+        for (num_chunks, chunk_bits) in [(6, 43), (4, 64), (3, 86)] {
+            let layout = ChunkLayout { num_chunks, chunk_bits };
+            let chunks = bigint_to_chunks(x.clone(), layout);
+            assert_eq!(chunks.len(), num_chunks);
+            assert!(chunks.iter().all(|c| c.parse::<BigUint>().unwrap().bits() <= chunk_bits as u64));
+            assert_eq!(chunks_to_bigint(&chunks, layout).unwrap(), x);
+        }
+    }
+
+    #[test]
+    fn chunks_to_bigint_rejects_malformed_chunks() {
+        let layout = ChunkLayout::default();
+        let oversized = (BigUint::from(1u32) << 43u32).to_string();
+        let mut chunks = vec!["0".to_string(); 6];
+        chunks[0] = oversized;
+        assert!(chunks_to_bigint(&chunks, layout).is_err());
+        assert!(chunks_to_bigint(&chunks[1..], layout).is_err());
+        assert!(chunks_to_bigint(&vec!["x".to_string(); 6], layout).is_err());
+    }
+
+    #[test]
+    fn chunk_layout_derives_missing_dimension() {
+        assert_eq!(ChunkLayout::resolve(None, None).unwrap(), ChunkLayout::default());
+        assert_eq!(ChunkLayout::resolve(None, Some(64)).unwrap(), ChunkLayout { num_chunks: 4, chunk_bits: 64 });
+        assert_eq!(ChunkLayout::resolve(Some(3), None).unwrap(), ChunkLayout { num_chunks: 3, chunk_bits: 86 });
+        assert!(ChunkLayout::resolve(Some(2), Some(64)).is_err());
+        assert!(ChunkLayout::resolve(None, Some(0)).is_err());
+    }
+
+    #[test]
+    fn hex_to_32_bytes_rejects_wide_values() {
+        assert_eq!(hex_to_32_bytes("0x1").unwrap(), bigint_to_32_bytes(&BigUint::from(1u32)));
+        assert!(hex_to_32_bytes(&format!("0x1{}", "0".repeat(64))).is_err());
+        assert!(hex_to_32_bytes("0xzz").is_err());
+    }
+}
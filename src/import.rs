@@ -0,0 +1,123 @@
+//! Import of an existing signature and public key as a test case
+
+use p256::ecdsa::{Signature, VerifyingKey};
+
+use crate::encoding::normalize_s;
+use crate::EcdsaTestCase;
+
+/// Decode a hex string, ignoring a `0x` prefix and any whitespace
+pub fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>, String> {
+    let digits: String = value.split_whitespace().collect();
+    hex::decode(digits.strip_prefix("0x").unwrap_or(&digits))
+        .map_err(|e| format!("{} is not valid hex: {}", field, e))
+}
+
+/// Parse a signature given either DER-encoded or as 64-byte compact r || s
+pub fn parse_signature(bytes: &[u8]) -> Result<Signature, String> {
+    // A 64-byte compact signature that also happens to be well-formed DER is
+    // vanishingly unlikely, so DER is tried first
+    if let Ok(signature) = Signature::from_der(bytes) {
+        return Ok(signature);
+    }
+    if bytes.len() == 64 {
+        return Signature::from_slice(bytes).map_err(|_| "compact signature r or s is zero or not below the curve order".to_string());
+    }
+    Err(format!("signature is {} bytes and not valid DER (expected DER or 64-byte compact r || s)", bytes.len()))
+}
+
+/// Parse a SEC1-encoded public key (compressed or uncompressed) into its x and y coordinates
+pub fn parse_public_key(bytes: &[u8]) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let verifying_key = VerifyingKey::from_sec1_bytes(bytes)
+        .map_err(|_| format!("public key ({} bytes) is not a SEC1-encoded P-256 point", bytes.len()))?;
+    let point = verifying_key.to_encoded_point(false);
+    Ok((point.as_bytes()[1..33].to_vec(), point.as_bytes()[33..65].to_vec()))
+}
+
+/// Build a test case from a signature (DER or compact), a SEC1 public key, and
+/// the 32-byte message hash it was signed over, with s normalized
+pub fn test_case_from_signature(signature: &[u8], public_key: &[u8], message_hash: &[u8]) -> Result<EcdsaTestCase, String> {
+    if message_hash.len() != 32 {
+        return Err(format!("message hash is {} bytes, expected 32", message_hash.len()));
+    }
+    let (r, s) = parse_signature(signature)?.split_bytes();
+    let (pubkey_x, pubkey_y) = parse_public_key(public_key)?;
+    Ok(EcdsaTestCase {
+        message_hash: message_hash.to_vec(),
+        pubkey_x,
+        pubkey_y,
+        r: r.to_vec(),
+        s: normalize_s(&s),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{bigint_to_32_bytes, bytes_to_bigint, curve_order};
+    use p256::ecdsa::{signature::Signer, SigningKey};
+    use rand::rngs::OsRng;
+    use sha2::{Digest, Sha256};
+
+    const MESSAGE: &[u8] = b"Test message for signature";
+
+    fn signed() -> (Signature, VerifyingKey) {
+        let signing_key = SigningKey::random(&mut OsRng);
+        (signing_key.sign(MESSAGE), *signing_key.verifying_key())
+    }
+
+    #[test]
+    fn der_and_compact_signatures_import_identically() {
+        let (signature, verifying_key) = signed();
+        let public_key = verifying_key.to_encoded_point(false);
+        let hash = Sha256::digest(MESSAGE);
+
+        let from_der = test_case_from_signature(signature.to_der().as_bytes(), public_key.as_bytes(), &hash).unwrap();
+        let from_compact = test_case_from_signature(&signature.to_bytes(), public_key.as_bytes(), &hash).unwrap();
+        assert_eq!(from_der, from_compact);
+        assert!(from_der.verify());
+    }
+
+    #[test]
+    fn compressed_public_key_imports() {
+        let (signature, verifying_key) = signed();
+        let compressed = verifying_key.to_encoded_point(true);
+        let uncompressed = verifying_key.to_encoded_point(false);
+        assert_eq!(parse_public_key(compressed.as_bytes()).unwrap(), parse_public_key(uncompressed.as_bytes()).unwrap());
+
+        let test_case = test_case_from_signature(&signature.to_bytes(), compressed.as_bytes(), &Sha256::digest(MESSAGE)).unwrap();
+        assert!(test_case.verify());
+    }
+
+    #[test]
+    fn high_s_is_normalized() {
+        let (signature, verifying_key) = signed();
+        let (r, s) = signature.split_bytes();
+        let high_s = bigint_to_32_bytes(&(curve_order() - bytes_to_bigint(&s)));
+        let test_case = test_case_from_signature(
+            &[r.as_slice(), &high_s].concat(),
+            verifying_key.to_encoded_point(false).as_bytes(),
+            &Sha256::digest(MESSAGE),
+        )
+        .unwrap();
+        assert_eq!(test_case.s, normalize_s(&s));
+        assert!(test_case.verify());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let (signature, verifying_key) = signed();
+        let public_key = verifying_key.to_encoded_point(false);
+        let hash = Sha256::digest(MESSAGE);
+
+        assert!(parse_signature(&signature.to_bytes()[..63]).is_err());
+        assert!(parse_signature(&[0u8; 64]).is_err());
+        assert!(parse_public_key(&public_key.as_bytes()[..64]).is_err());
+        assert!(test_case_from_signature(&signature.to_bytes(), public_key.as_bytes(), &hash[..31]).is_err());
+    }
+
+    #[test]
+    fn decode_hex_accepts_prefix_and_whitespace() {
+        assert_eq!(decode_hex("x", "0xdead beef\n").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+        assert!(decode_hex("x", "0xabc").is_err());
+    }
+}
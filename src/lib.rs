@@ -0,0 +1,268 @@
+//! ECDSA (P-256) test case generation shared by the `generate_test_cases` binary.
+//!
+//! A test case is held as raw 32-byte values in [`EcdsaTestCase`] and turned
+//! into each stack's input format by a [`StackWriter`].
+
+pub mod encoding;
+pub mod import;
+pub mod webauthn;
+pub mod writers;
+
+use p256::ecdsa::{SigningKey, Signature, VerifyingKey, signature::hazmat::{PrehashSigner, PrehashVerifier}};
+use p256::EncodedPoint;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use std::str::FromStr;
+
+pub use encoding::ChunkLayout;
+pub use webauthn::WebAuthnAssertion;
+pub use writers::{GnarkWriter, NoirWriter, SnarkjsWriter, Stack, StackWriter};
+
+/// Ways an otherwise valid signature is corrupted for soundness checks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corruption {
+    R,
+    S,
+    MsgHash,
+    PubKey,
+}
+
+impl Corruption {
+    pub const ALL: [Corruption; 4] = [Corruption::R, Corruption::S, Corruption::MsgHash, Corruption::PubKey];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Corruption::R => "r",
+            Corruption::S => "s",
+            Corruption::MsgHash => "msghash",
+            Corruption::PubKey => "pubkey",
+        }
+    }
+}
+
+/// Hash function that turns a message into the digest the circuits verify
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HashFunction {
+    #[default]
+    Sha256,
+    Keccak256,
+    /// The message is already a 32-byte digest
+    None,
+}
+
+impl HashFunction {
+    pub const ALL: [HashFunction; 3] = [HashFunction::Sha256, HashFunction::Keccak256, HashFunction::None];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashFunction::Sha256 => "sha256",
+            HashFunction::Keccak256 => "keccak256",
+            HashFunction::None => "none",
+        }
+    }
+
+    /// Hash `message` into a 32-byte digest (with `None`, `message` must already be one)
+    pub fn digest(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            HashFunction::Sha256 => Ok(Sha256::digest(message).to_vec()),
+            HashFunction::Keccak256 => Ok(Keccak256::digest(message).to_vec()),
+            HashFunction::None if message.len() == 32 => Ok(message.to_vec()),
+            HashFunction::None => Err(format!("pre-hashed digest is {} bytes, expected 32", message.len())),
+        }
+    }
+}
+
+impl FromStr for HashFunction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HashFunction::ALL
+            .into_iter()
+            .find(|hash| hash.name() == s)
+            .ok_or_else(|| format!("unknown hash function '{}' (expected sha256, keccak256, or none)", s))
+    }
+}
+
+impl Serialize for HashFunction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for HashFunction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Manifest entry describing one generated test case
+#[derive(Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub name: String,
+    pub expected: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corruption: Option<String>,
+    /// Where an imported test case came from (absent for generated ones)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Manifest written alongside the test cases in every stack's tests directory
+#[derive(Serialize, Deserialize)]
+pub struct Manifest {
+    /// Layout of the snarkjs/rapidsnark chunks (older manifests predate this and use 6×43)
+    #[serde(default)]
+    pub chunk_layout: ChunkLayout,
+    /// Hash function the message digests were produced with (older manifests predate this and use SHA-256)
+    #[serde(default)]
+    pub hash: HashFunction,
+    /// Signatures in each test case file (older manifests predate batching and hold one)
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    pub test_cases: Vec<ManifestEntry>,
+}
+
+fn default_batch_size() -> usize {
+    1
+}
+
+/// Signature, public key, and message hash of one test case, each as 32 big-endian bytes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EcdsaTestCase {
+    pub message_hash: Vec<u8>,
+    pub pubkey_x: Vec<u8>,
+    pub pubkey_y: Vec<u8>,
+    pub r: Vec<u8>,
+    pub s: Vec<u8>,
+}
+
+impl EcdsaTestCase {
+    /// Sign the 32-byte digest `message_hash` directly with a fresh random key,
+    /// whichever hash function produced it
+    pub fn sign_prehash(message_hash: &[u8]) -> Self {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature = signing_key.sign_prehash(message_hash).expect("Failed to sign message hash");
+        Self::from_signature(signing_key.verifying_key(), signature, message_hash)
+    }
+
+    /// Apply `corruption` to an otherwise valid test case
+    pub fn corrupt(mut self, corruption: Corruption) -> Self {
+        match corruption {
+            Corruption::R => self.r = encoding::increment_scalar(&self.r),
+            Corruption::S => self.s = encoding::increment_scalar(&self.s),
+            Corruption::MsgHash => self.message_hash[31] ^= 1,
+            Corruption::PubKey => {
+                // A mismatched pubkey is still a valid curve point, just not the signer's
+                let other_key = SigningKey::random(&mut OsRng);
+                let pubkey_bytes = other_key.verifying_key().to_encoded_point(false);
+                self.pubkey_x = pubkey_bytes.as_bytes()[1..33].to_vec();
+                self.pubkey_y = pubkey_bytes.as_bytes()[33..65].to_vec();
+            }
+        }
+        self
+    }
+
+    fn from_signature(verifying_key: &VerifyingKey, signature: Signature, message_hash: &[u8]) -> Self {
+        let signature_bytes = signature.to_bytes();
+        let (r, s) = signature_bytes.split_at(32);
+
+        let pubkey_bytes = verifying_key.to_encoded_point(false);
+        EcdsaTestCase {
+            message_hash: message_hash.to_vec(),
+            pubkey_x: pubkey_bytes.as_bytes()[1..33].to_vec(),
+            pubkey_y: pubkey_bytes.as_bytes()[33..65].to_vec(),
+            r: r.to_vec(),
+            // Normalize s value according to BIP-0062
+            s: encoding::normalize_s(s),
+        }
+    }
+
+    /// Verify the signature over the (already hashed) message with the p256 crate.
+    /// A test case whose fields are not 32 bytes each never verifies.
+    pub fn verify(&self) -> bool {
+        if [&self.message_hash, &self.pubkey_x, &self.pubkey_y, &self.r, &self.s].iter().any(|field| field.len() != 32) {
+            return false;
+        }
+        let point = EncodedPoint::from_affine_coordinates(
+            self.pubkey_x.as_slice().into(),
+            self.pubkey_y.as_slice().into(),
+            false,
+        );
+        let Ok(verifying_key) = VerifyingKey::from_encoded_point(&point) else {
+            return false;
+        };
+        let Ok(signature) = Signature::from_slice(&[self.r.as_slice(), &self.s].concat()) else {
+            return false;
+        };
+        verifying_key.verify_prehash(&self.message_hash, &signature).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"Test message for signature";
+
+    #[test]
+    fn signed_test_case_verifies() {
+        let test_case = EcdsaTestCase::sign_prehash(&Sha256::digest(MESSAGE));
+        assert!(test_case.verify());
+    }
+
+    #[test]
+    fn wrong_message_hash_does_not_verify() {
+        let mut test_case = EcdsaTestCase::sign_prehash(&Sha256::digest(MESSAGE));
+        test_case.message_hash = Sha256::digest(b"another message").to_vec();
+        assert!(!test_case.verify());
+    }
+
+    #[test]
+    fn corrupted_test_cases_do_not_verify() {
+        for corruption in Corruption::ALL {
+            let test_case = EcdsaTestCase::sign_prehash(&Sha256::digest(MESSAGE)).corrupt(corruption);
+            assert!(!test_case.verify(), "{} corruption still verifies", corruption.name());
+        }
+    }
+
+    #[test]
+    fn test_case_with_short_fields_does_not_verify() {
+        let mut test_case = EcdsaTestCase::sign_prehash(&Sha256::digest(MESSAGE));
+        test_case.pubkey_x.remove(0);
+        assert!(!test_case.verify());
+    }
+
+    #[test]
+    fn prehash_signatures_verify_for_every_hash_function() {
+        for hash in [HashFunction::Sha256, HashFunction::Keccak256] {
+            let test_case = EcdsaTestCase::sign_prehash(&hash.digest(MESSAGE).unwrap());
+            assert!(test_case.verify(), "{} signature does not verify", hash.name());
+            assert!(!test_case.corrupt(Corruption::MsgHash).verify());
+        }
+        let digest = Sha256::digest(MESSAGE);
+        assert_eq!(HashFunction::None.digest(&digest).unwrap(), digest.to_vec());
+        assert!(HashFunction::None.digest(MESSAGE).is_err());
+    }
+
+    #[test]
+    fn keccak256_digest_matches_ethereum() {
+        // keccak256("") as used by Ethereum (not NIST SHA3-256)
+        assert_eq!(
+            hex::encode(HashFunction::Keccak256.digest(b"").unwrap()),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[test]
+    fn hash_function_round_trips_through_manifest() {
+        for hash in HashFunction::ALL {
+            assert_eq!(hash.name().parse::<HashFunction>().unwrap(), hash);
+        }
+        assert!("sha3".parse::<HashFunction>().is_err());
+        let manifest: Manifest = serde_json::from_str(r#"{"test_cases": []}"#).unwrap();
+        assert_eq!(manifest.hash, HashFunction::Sha256);
+        assert_eq!(manifest.batch_size, 1);
+    }
+}
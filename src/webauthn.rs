@@ -0,0 +1,195 @@
+//! Import of real WebAuthn (passkey) assertions as test cases
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ciborium::Value;
+use p256::ecdsa::Signature;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::encoding::normalize_s;
+use crate::EcdsaTestCase;
+
+/// One WebAuthn assertion as returned by `navigator.credentials.get()`, with
+/// every field base64url-encoded. `publicKey` is the credential's COSE key
+/// saved at registration.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebAuthnAssertion {
+    pub authenticator_data: String,
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    pub signature: String,
+    pub public_key: String,
+}
+
+/// Parse a JSON file holding either one assertion or an array of them
+pub fn parse_assertions(json: &str) -> Result<Vec<WebAuthnAssertion>, String> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(WebAuthnAssertion),
+        Many(Vec<WebAuthnAssertion>),
+    }
+    match serde_json::from_str(json).map_err(|e| e.to_string())? {
+        OneOrMany::One(assertion) => Ok(vec![assertion]),
+        OneOrMany::Many(assertions) => Ok(assertions),
+    }
+}
+
+/// Decode a base64url field, with or without padding
+fn decode_base64url(field: &str, value: &str) -> Result<Vec<u8>, String> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|e| format!("{} is not valid base64url: {}", field, e))
+}
+
+/// Extract the P-256 coordinates from a COSE_Key (kty EC2, alg ES256, crv P-256)
+pub fn cose_key_coordinates(cose_key: &[u8]) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let value: Value = ciborium::from_reader(cose_key).map_err(|e| format!("invalid COSE key: {}", e))?;
+    let map = value.as_map().ok_or("COSE key is not a CBOR map")?;
+    let get = |label: i128| {
+        map.iter()
+            .find(|(key, _)| key.as_integer().is_some_and(|key| i128::from(key) == label))
+            .map(|(_, value)| value)
+    };
+    let int = |label| get(label).and_then(Value::as_integer).map(i128::from);
+
+    if int(1) != Some(2) {
+        return Err("COSE key type must be EC2 (2)".to_string());
+    }
+    // alg is optional in a COSE_Key, but if present it must be ES256
+    if int(3).is_some_and(|alg| alg != -7) {
+        return Err("COSE key algorithm must be ES256 (-7)".to_string());
+    }
+    if int(-1) != Some(1) {
+        return Err("COSE key curve must be P-256 (1)".to_string());
+    }
+    let coordinate = |label, name| {
+        get(label)
+            .and_then(Value::as_bytes)
+            .filter(|bytes| bytes.len() == 32)
+            .cloned()
+            .ok_or_else(|| format!("COSE key {} coordinate must be 32 bytes", name))
+    };
+    Ok((coordinate(-2, "x")?, coordinate(-3, "y")?))
+}
+
+impl WebAuthnAssertion {
+    /// Recompute the digest the authenticator signed,
+    /// SHA-256(authenticatorData || SHA-256(clientDataJSON)), and build a test
+    /// case from it with s normalized
+    pub fn to_test_case(&self) -> Result<EcdsaTestCase, String> {
+        let authenticator_data = decode_base64url("authenticatorData", &self.authenticator_data)?;
+        // rpIdHash (32 bytes) + flags (1 byte) + signCount (4 bytes)
+        if authenticator_data.len() < 37 {
+            return Err(format!("authenticatorData is {} bytes, expected at least 37", authenticator_data.len()));
+        }
+
+        let client_data_json = decode_base64url("clientDataJSON", &self.client_data_json)?;
+        let client_data: serde_json::Value = serde_json::from_slice(&client_data_json)
+            .map_err(|e| format!("clientDataJSON is not valid JSON: {}", e))?;
+        if client_data.get("type").and_then(|t| t.as_str()) != Some("webauthn.get") {
+            return Err("clientDataJSON type must be webauthn.get".to_string());
+        }
+
+        let signature = Signature::from_der(&decode_base64url("signature", &self.signature)?)
+            .map_err(|e| format!("signature is not a valid DER ECDSA signature: {}", e))?;
+        let (pubkey_x, pubkey_y) = cose_key_coordinates(&decode_base64url("publicKey", &self.public_key)?)?;
+
+        let client_data_hash = Sha256::digest(&client_data_json);
+        let message_hash = Sha256::digest([authenticator_data.as_slice(), &client_data_hash].concat()).to_vec();
+        let (r, s) = signature.split_bytes();
+
+        Ok(EcdsaTestCase {
+            message_hash,
+            pubkey_x,
+            pubkey_y,
+            r: r.to_vec(),
+            s: normalize_s(&s),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{signature::Signer, SigningKey};
+    use rand::rngs::OsRng;
+
+    fn cose_key(kty: i64, alg: i64, crv: i64, x: &[u8], y: &[u8]) -> Vec<u8> {
+        let value = Value::Map(vec![
+            (Value::from(1), Value::from(kty)),
+            (Value::from(3), Value::from(alg)),
+            (Value::from(-1), Value::from(crv)),
+            (Value::from(-2), Value::Bytes(x.to_vec())),
+            (Value::from(-3), Value::Bytes(y.to_vec())),
+        ]);
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&value, &mut bytes).unwrap();
+        bytes
+    }
+
+    /// Sign an assertion the way an authenticator does: over authenticatorData || SHA-256(clientDataJSON)
+    fn signed_assertion() -> (WebAuthnAssertion, Vec<u8>) {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let point = signing_key.verifying_key().to_encoded_point(false);
+
+        let authenticator_data = [vec![0xaa; 32], vec![0x05], vec![0, 0, 0, 7]].concat();
+        let client_data_json = br#"{"type":"webauthn.get","challenge":"dGVzdA","origin":"https://example.com"}"#;
+        let signed = [authenticator_data.as_slice(), &Sha256::digest(client_data_json)].concat();
+        let signature: Signature = signing_key.sign(&signed);
+
+        let assertion = WebAuthnAssertion {
+            authenticator_data: URL_SAFE_NO_PAD.encode(&authenticator_data),
+            client_data_json: URL_SAFE_NO_PAD.encode(client_data_json),
+            signature: URL_SAFE_NO_PAD.encode(signature.to_der().as_bytes()),
+            public_key: URL_SAFE_NO_PAD.encode(cose_key(2, -7, 1, point.x().unwrap(), point.y().unwrap())),
+        };
+        (assertion, Sha256::digest(&signed).to_vec())
+    }
+
+    #[test]
+    fn imported_assertion_verifies() {
+        let (assertion, expected_hash) = signed_assertion();
+        let test_case = assertion.to_test_case().unwrap();
+        assert_eq!(test_case.message_hash, expected_hash);
+        assert_eq!(normalize_s(&test_case.s), test_case.s);
+        assert!(test_case.verify());
+    }
+
+    #[test]
+    fn tampered_client_data_does_not_verify() {
+        let (mut assertion, _) = signed_assertion();
+        assertion.client_data_json =
+            URL_SAFE_NO_PAD.encode(br#"{"type":"webauthn.get","challenge":"b3RoZXI","origin":"https://example.com"}"#);
+        assert!(!assertion.to_test_case().unwrap().verify());
+    }
+
+    #[test]
+    fn rejects_non_assertion_client_data() {
+        let (mut assertion, _) = signed_assertion();
+        assertion.client_data_json = URL_SAFE_NO_PAD.encode(br#"{"type":"webauthn.create"}"#);
+        assert!(assertion.to_test_case().is_err());
+    }
+
+    #[test]
+    fn rejects_non_p256_cose_keys() {
+        let x = [1u8; 32];
+        assert!(cose_key_coordinates(&cose_key(2, -7, 1, &x, &x)).is_ok());
+        assert!(cose_key_coordinates(&cose_key(1, -7, 1, &x, &x)).is_err());
+        assert!(cose_key_coordinates(&cose_key(2, -8, 1, &x, &x)).is_err());
+        assert!(cose_key_coordinates(&cose_key(2, -7, 2, &x, &x)).is_err());
+        assert!(cose_key_coordinates(&cose_key(2, -7, 1, &x[..31], &x)).is_err());
+    }
+
+    #[test]
+    fn parses_single_assertion_or_array() {
+        let (assertion, _) = signed_assertion();
+        let one = serde_json::to_string(&assertion).unwrap();
+        assert!(one.contains("\"clientDataJSON\""));
+        assert_eq!(parse_assertions(&one).unwrap().len(), 1);
+        assert_eq!(parse_assertions(&format!("[{},{}]", one, one)).unwrap().len(), 2);
+        assert!(parse_assertions("{}").is_err());
+    }
+}
@@ -0,0 +1,485 @@
+//! Per-stack test case file formats
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::encoding::{
+    bigint_to_chunks, bigint_to_hex, bytes_to_bigint, checked_32_bytes, chunks_to_bigint,
+    hex_to_32_bytes, pack_bytes, unpack_fields, ChunkLayout,
+};
+use crate::EcdsaTestCase;
+
+/// Serializes test cases to, and parses them back from, one stack's input format
+pub trait StackWriter {
+    /// File extension of this stack's test case files
+    fn extension(&self) -> &'static str;
+
+    /// Render a test case in this stack's format
+    fn render(&self, test_case: &EcdsaTestCase) -> String;
+
+    /// Parse a test case back from this stack's format
+    fn parse(&self, contents: &str) -> Result<EcdsaTestCase, String>;
+
+    /// Render several independent test cases as one batched input, with each
+    /// value becoming an array holding one entry per signature
+    fn render_batch(&self, batch: &[EcdsaTestCase]) -> String;
+
+    /// Parse a batched input back into its test cases
+    fn parse_batch(&self, contents: &str) -> Result<Vec<EcdsaTestCase>, String>;
+
+    /// Write `test_case` to `<dir>/<name>.<extension>`
+    fn write(&self, dir: &Path, name: &str, test_case: &EcdsaTestCase) -> std::io::Result<()> {
+        fs::write(dir.join(format!("{}.{}", name, self.extension())), self.render(test_case))
+    }
+
+    /// Read a test case back from a file written by [`StackWriter::write`]
+    fn read(&self, path: &Path) -> Result<EcdsaTestCase, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        self.parse(&contents)
+    }
+
+    /// Write `batch` to `<dir>/<name>.<extension>`; a batch of one is written
+    /// in the single test case format
+    fn write_batch(&self, dir: &Path, name: &str, batch: &[EcdsaTestCase]) -> std::io::Result<()> {
+        match batch {
+            [test_case] => self.write(dir, name, test_case),
+            _ => fs::write(dir.join(format!("{}.{}", name, self.extension())), self.render_batch(batch)),
+        }
+    }
+
+    /// Read a batch of `batch_size` test cases back from a file written by
+    /// [`StackWriter::write_batch`]
+    fn read_batch(&self, path: &Path, batch_size: usize) -> Result<Vec<EcdsaTestCase>, String> {
+        if batch_size == 1 {
+            return self.read(path).map(|test_case| vec![test_case]);
+        }
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let batch = self.parse_batch(&contents)?;
+        if batch.len() != batch_size {
+            return Err(format!("batch holds {} signatures, expected {}", batch.len(), batch_size));
+        }
+        Ok(batch)
+    }
+}
+
+/// Check that every per-signature array of a batched input has the same length
+fn batch_len(lengths: &[usize]) -> Result<usize, String> {
+    match lengths {
+        [first, rest @ ..] if rest.iter().all(|len| len == first) => Ok(*first),
+        _ => Err("batched values must all hold the same number of signatures".to_string()),
+    }
+}
+
+/// Test case data for snarkjs/rapidsnark
+#[derive(Serialize, Deserialize)]
+struct SnarkjsTestCase {
+    r: Vec<String>,
+    s: Vec<String>,
+    msghash: Vec<String>,
+    pubkey: Vec<Vec<String>>,
+}
+
+/// Batched test case data for snarkjs/rapidsnark, one entry per signature
+#[derive(Serialize, Deserialize)]
+struct SnarkjsBatch {
+    r: Vec<Vec<String>>,
+    s: Vec<Vec<String>>,
+    msghash: Vec<Vec<String>>,
+    pubkey: Vec<Vec<Vec<String>>>,
+}
+
+/// Test case data for gnark (uses hex strings)
+#[derive(Serialize, Deserialize)]
+struct GnarkTestCase {
+    r: String,
+    s: String,
+    msghash: String,
+    pubkey_x: String,
+    pubkey_y: String,
+}
+
+/// Batched test case data for gnark, one hex string per signature
+#[derive(Serialize, Deserialize)]
+struct GnarkBatch {
+    r: Vec<String>,
+    s: Vec<String>,
+    msghash: Vec<String>,
+    pubkey_x: Vec<String>,
+    pubkey_y: Vec<String>,
+}
+
+/// snarkjs/rapidsnark: JSON with every value split into chunks
+pub struct SnarkjsWriter {
+    pub layout: ChunkLayout,
+}
+
+impl SnarkjsWriter {
+    fn chunk(&self, test_case: &EcdsaTestCase) -> SnarkjsTestCase {
+        let chunks = |bytes: &[u8]| bigint_to_chunks(bytes_to_bigint(bytes), self.layout);
+        SnarkjsTestCase {
+            r: chunks(&test_case.r),
+            s: chunks(&test_case.s),
+            msghash: chunks(&test_case.message_hash),
+            pubkey: vec![
+                chunks(&test_case.pubkey_x),
+                chunks(&test_case.pubkey_y),
+            ],
+        }
+    }
+
+    fn unchunk(&self, test_case: SnarkjsTestCase) -> Result<EcdsaTestCase, String> {
+        if test_case.pubkey.len() != 2 {
+            return Err("pubkey must have x and y coordinates".to_string());
+        }
+        let bytes = |chunks: &[String]| checked_32_bytes(&chunks_to_bigint(chunks, self.layout)?);
+        Ok(EcdsaTestCase {
+            message_hash: bytes(&test_case.msghash)?,
+            pubkey_x: bytes(&test_case.pubkey[0])?,
+            pubkey_y: bytes(&test_case.pubkey[1])?,
+            r: bytes(&test_case.r)?,
+            s: bytes(&test_case.s)?,
+        })
+    }
+}
+
+impl StackWriter for SnarkjsWriter {
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn render(&self, test_case: &EcdsaTestCase) -> String {
+        serde_json::to_string_pretty(&self.chunk(test_case))
+            .expect("Failed to serialize snarkjs test case")
+    }
+
+    fn parse(&self, contents: &str) -> Result<EcdsaTestCase, String> {
+        let test_case: SnarkjsTestCase = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        self.unchunk(test_case)
+    }
+
+    fn render_batch(&self, batch: &[EcdsaTestCase]) -> String {
+        let test_cases: Vec<SnarkjsTestCase> = batch.iter().map(|test_case| self.chunk(test_case)).collect();
+        let snarkjs_batch = SnarkjsBatch {
+            r: test_cases.iter().map(|test_case| test_case.r.clone()).collect(),
+            s: test_cases.iter().map(|test_case| test_case.s.clone()).collect(),
+            msghash: test_cases.iter().map(|test_case| test_case.msghash.clone()).collect(),
+            pubkey: test_cases.into_iter().map(|test_case| test_case.pubkey).collect(),
+        };
+        serde_json::to_string_pretty(&snarkjs_batch)
+            .expect("Failed to serialize snarkjs test case batch")
+    }
+
+    fn parse_batch(&self, contents: &str) -> Result<Vec<EcdsaTestCase>, String> {
+        let batch: SnarkjsBatch = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        let len = batch_len(&[batch.r.len(), batch.s.len(), batch.msghash.len(), batch.pubkey.len()])?;
+        (0..len)
+            .map(|i| {
+                self.unchunk(SnarkjsTestCase {
+                    r: batch.r[i].clone(),
+                    s: batch.s[i].clone(),
+                    msghash: batch.msghash[i].clone(),
+                    pubkey: batch.pubkey[i].clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Noir: TOML with every value packed into Field elements
+pub struct NoirWriter;
+
+/// Format a 32-byte value as Noir Field values (matches Noir's pack_bytes logic)
+fn format_field_array(bytes: &[u8]) -> String {
+    let fields = pack_bytes(bytes);
+    if fields.len() == 1 {
+        format!("\"{}\"", fields[0])
+    } else {
+        let quoted_fields: Vec<String> = fields.iter().map(|f| format!("\"{}\"", f)).collect();
+        format!("[{}]", quoted_fields.join(", "))
+    }
+}
+
+/// Unpack one TOML value written by [`format_field_array`]; single-field values
+/// are written as a plain string rather than an array
+fn parse_field_array(key: &str, value: Option<&toml::Value>) -> Result<Vec<u8>, String> {
+    let fields = match value {
+        Some(toml::Value::String(value)) => vec![value.clone()],
+        Some(toml::Value::Array(values)) => values
+            .iter()
+            .map(|v| v.as_str().map(str::to_string).ok_or_else(|| format!("{} must contain strings", key)))
+            .collect::<Result<_, _>>()?,
+        _ => return Err(format!("missing {}", key)),
+    };
+    unpack_fields(&fields, 32)
+}
+
+impl StackWriter for NoirWriter {
+    fn extension(&self) -> &'static str {
+        "toml"
+    }
+
+    fn render(&self, test_case: &EcdsaTestCase) -> String {
+        format!(
+            r#"# Field values (matching Noir's pack_bytes - 31-byte chunks)
+hashed_message = {}
+pub_key_x = {}
+pub_key_y = {}
+signature_r = {}
+signature_s = {}
+"#,
+            format_field_array(&test_case.message_hash),
+            format_field_array(&test_case.pubkey_x),
+            format_field_array(&test_case.pubkey_y),
+            format_field_array(&test_case.r),
+            format_field_array(&test_case.s),
+        )
+    }
+
+    fn parse(&self, contents: &str) -> Result<EcdsaTestCase, String> {
+        let table: toml::Table = contents.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let field = |key: &str| parse_field_array(key, table.get(key));
+        Ok(EcdsaTestCase {
+            message_hash: field("hashed_message")?,
+            pubkey_x: field("pub_key_x")?,
+            pubkey_y: field("pub_key_y")?,
+            r: field("signature_r")?,
+            s: field("signature_s")?,
+        })
+    }
+
+    fn render_batch(&self, batch: &[EcdsaTestCase]) -> String {
+        let batched = |value: fn(&EcdsaTestCase) -> &[u8]| -> String {
+            let values: Vec<String> = batch.iter().map(|test_case| format_field_array(value(test_case))).collect();
+            format!("[{}]", values.join(", "))
+        };
+        format!(
+            r#"# Field values (matching Noir's pack_bytes - 31-byte chunks), one entry per signature
+hashed_message = {}
+pub_key_x = {}
+pub_key_y = {}
+signature_r = {}
+signature_s = {}
+"#,
+            batched(|test_case| &test_case.message_hash),
+            batched(|test_case| &test_case.pubkey_x),
+            batched(|test_case| &test_case.pubkey_y),
+            batched(|test_case| &test_case.r),
+            batched(|test_case| &test_case.s),
+        )
+    }
+
+    fn parse_batch(&self, contents: &str) -> Result<Vec<EcdsaTestCase>, String> {
+        let table: toml::Table = contents.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let field = |key: &str| -> Result<Vec<Vec<u8>>, String> {
+            match table.get(key) {
+                // Each entry is a full [f0, f1] array, which also tells a batch apart from a single test case
+                Some(toml::Value::Array(values)) if values.iter().all(toml::Value::is_array) => {
+                    values.iter().map(|value| parse_field_array(key, Some(value))).collect()
+                }
+                _ => Err(format!("{} must be an array of Field arrays, one per signature", key)),
+            }
+        };
+        let (message_hash, pubkey_x, pubkey_y, r, s) =
+            (field("hashed_message")?, field("pub_key_x")?, field("pub_key_y")?, field("signature_r")?, field("signature_s")?);
+        let len = batch_len(&[message_hash.len(), pubkey_x.len(), pubkey_y.len(), r.len(), s.len()])?;
+        Ok((0..len)
+            .map(|i| EcdsaTestCase {
+                message_hash: message_hash[i].clone(),
+                pubkey_x: pubkey_x[i].clone(),
+                pubkey_y: pubkey_y[i].clone(),
+                r: r[i].clone(),
+                s: s[i].clone(),
+            })
+            .collect())
+    }
+}
+
+/// gnark: JSON with every value as a 0x-prefixed hex string
+pub struct GnarkWriter;
+
+impl StackWriter for GnarkWriter {
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn render(&self, test_case: &EcdsaTestCase) -> String {
+        let hex = |bytes: &[u8]| bigint_to_hex(bytes_to_bigint(bytes));
+        let gnark_test_case = GnarkTestCase {
+            r: hex(&test_case.r),
+            s: hex(&test_case.s),
+            msghash: hex(&test_case.message_hash),
+            pubkey_x: hex(&test_case.pubkey_x),
+            pubkey_y: hex(&test_case.pubkey_y),
+        };
+        serde_json::to_string_pretty(&gnark_test_case)
+            .expect("Failed to serialize gnark test case")
+    }
+
+    fn parse(&self, contents: &str) -> Result<EcdsaTestCase, String> {
+        let test_case: GnarkTestCase = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        Ok(EcdsaTestCase {
+            message_hash: hex_to_32_bytes(&test_case.msghash)?,
+            pubkey_x: hex_to_32_bytes(&test_case.pubkey_x)?,
+            pubkey_y: hex_to_32_bytes(&test_case.pubkey_y)?,
+            r: hex_to_32_bytes(&test_case.r)?,
+            s: hex_to_32_bytes(&test_case.s)?,
+        })
+    }
+
+    fn render_batch(&self, batch: &[EcdsaTestCase]) -> String {
+        let hex = |value: fn(&EcdsaTestCase) -> &[u8]| -> Vec<String> {
+            batch.iter().map(|test_case| bigint_to_hex(bytes_to_bigint(value(test_case)))).collect()
+        };
+        let gnark_batch = GnarkBatch {
+            r: hex(|test_case| &test_case.r),
+            s: hex(|test_case| &test_case.s),
+            msghash: hex(|test_case| &test_case.message_hash),
+            pubkey_x: hex(|test_case| &test_case.pubkey_x),
+            pubkey_y: hex(|test_case| &test_case.pubkey_y),
+        };
+        serde_json::to_string_pretty(&gnark_batch)
+            .expect("Failed to serialize gnark test case batch")
+    }
+
+    fn parse_batch(&self, contents: &str) -> Result<Vec<EcdsaTestCase>, String> {
+        let batch: GnarkBatch = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        let len = batch_len(&[batch.r.len(), batch.s.len(), batch.msghash.len(), batch.pubkey_x.len(), batch.pubkey_y.len()])?;
+        (0..len)
+            .map(|i| {
+                Ok(EcdsaTestCase {
+                    message_hash: hex_to_32_bytes(&batch.msghash[i])?,
+                    pubkey_x: hex_to_32_bytes(&batch.pubkey_x[i])?,
+                    pubkey_y: hex_to_32_bytes(&batch.pubkey_y[i])?,
+                    r: hex_to_32_bytes(&batch.r[i])?,
+                    s: hex_to_32_bytes(&batch.s[i])?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Benchmarked proving stacks, each with its own tests directory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stack {
+    Snarkjs,
+    Rapidsnark,
+    Noir,
+    Gnark,
+}
+
+impl Stack {
+    pub const ALL: [Stack; 4] = [Stack::Snarkjs, Stack::Rapidsnark, Stack::Noir, Stack::Gnark];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stack::Snarkjs => "snarkjs",
+            Stack::Rapidsnark => "rapidsnark",
+            Stack::Noir => "noir",
+            Stack::Gnark => "gnark",
+        }
+    }
+
+    /// Directory the stack reads its test cases from, relative to the repository root
+    pub fn tests_dir(&self) -> PathBuf {
+        Path::new(self.name()).join("tests")
+    }
+
+    /// Writer for this stack's format (`layout` only affects snarkjs/rapidsnark)
+    pub fn writer(&self, layout: ChunkLayout) -> Box<dyn StackWriter> {
+        match self {
+            Stack::Snarkjs | Stack::Rapidsnark => Box::new(SnarkjsWriter { layout }),
+            Stack::Noir => Box::new(NoirWriter),
+            Stack::Gnark => Box::new(GnarkWriter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_test_case() -> EcdsaTestCase {
+        EcdsaTestCase {
+            message_hash: (0..32).collect(),
+            pubkey_x: vec![0xff; 32],
+            pubkey_y: (100..132).collect(),
+            r: [vec![0u8; 31], vec![1]].concat(),
+            s: vec![0u8; 32],
+        }
+    }
+
+    #[test]
+    fn every_writer_round_trips() {
+        let test_case = sample_test_case();
+        for stack in Stack::ALL {
+            for layout in [ChunkLayout::default(), ChunkLayout { num_chunks: 4, chunk_bits: 64 }] {
+                let writer = stack.writer(layout);
+                let parsed = writer.parse(&writer.render(&test_case)).unwrap();
+                assert_eq!(parsed, test_case, "{} does not round-trip", stack.name());
+            }
+        }
+    }
+
+    #[test]
+    fn every_writer_round_trips_batches() {
+        let mut other = sample_test_case();
+        other.r = vec![0x42; 32];
+        let batch = vec![sample_test_case(), other, sample_test_case()];
+        for stack in Stack::ALL {
+            let writer = stack.writer(ChunkLayout::default());
+            let parsed = writer.parse_batch(&writer.render_batch(&batch)).unwrap();
+            assert_eq!(parsed, batch, "{} does not round-trip batches", stack.name());
+            // A batch is not mistaken for a single test case, nor the other way around
+            assert!(writer.parse(&writer.render_batch(&batch)).is_err());
+            assert!(writer.parse_batch(&writer.render(&batch[0])).is_err());
+        }
+    }
+
+    #[test]
+    fn batch_of_one_is_written_as_a_single_test_case() {
+        let dir = std::env::temp_dir().join(format!("testcase_gen_batch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for stack in Stack::ALL {
+            let writer = stack.writer(ChunkLayout::default());
+            writer.write_batch(&dir, "one", &[sample_test_case()]).unwrap();
+            let path = dir.join(format!("one.{}", writer.extension()));
+            assert_eq!(fs::read_to_string(&path).unwrap(), writer.render(&sample_test_case()));
+            assert_eq!(writer.read_batch(&path, 1).unwrap(), vec![sample_test_case()]);
+
+            writer.write_batch(&dir, "two", &[sample_test_case(), sample_test_case()]).unwrap();
+            let path = dir.join(format!("two.{}", writer.extension()));
+            assert_eq!(writer.read_batch(&path, 2).unwrap().len(), 2);
+            assert!(writer.read_batch(&path, 3).is_err());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn snarkjs_writer_uses_its_layout() {
+        let writer = SnarkjsWriter { layout: ChunkLayout { num_chunks: 3, chunk_bits: 86 } };
+        let json: serde_json::Value = serde_json::from_str(&writer.render(&sample_test_case())).unwrap();
+        assert_eq!(json["r"], serde_json::json!(["1", "0", "0"]));
+        assert_eq!(json["pubkey"].as_array().unwrap().len(), 2);
+
+        // Chunks written with one layout are rejected under another
+        let other = SnarkjsWriter { layout: ChunkLayout::default() };
+        assert!(other.parse(&writer.render(&sample_test_case())).is_err());
+    }
+
+    #[test]
+    fn noir_writer_emits_packed_fields() {
+        let toml = NoirWriter.render(&sample_test_case());
+        assert!(toml.contains("signature_s = [\"0\", \"0\"]"));
+        assert!(toml.contains("signature_r = [\"0\", \"1\"]"));
+    }
+
+    #[test]
+    fn gnark_writer_emits_unpadded_hex() {
+        let json: serde_json::Value = serde_json::from_str(&GnarkWriter.render(&sample_test_case())).unwrap();
+        assert_eq!(json["r"], "0x1");
+        assert_eq!(json["s"], "0x0");
+        assert_eq!(json["pubkey_x"], format!("0x{}", "f".repeat(64)));
+    }
+}